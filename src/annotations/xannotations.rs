@@ -0,0 +1,315 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 17:30
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::list_to_values;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum XHeuristicType {
+        Static,
+        Random,
+        Min,
+        Max,
+    }
+
+    /// a part of a variable or value heuristic, the variables are resolved into their ids
+    #[derive(Clone, Debug)]
+    pub struct XHeuristicPart {
+        kind: XHeuristicType,
+        /// the type of min and max, eg. dom or wdeg
+        criterion: Option<String>,
+        /// the order of values, only for static value heuristics
+        order: Vec<i64>,
+        vars: Vec<String>,
+    }
+
+    impl XHeuristicPart {
+        pub fn get_kind(&self) -> &XHeuristicType {
+            &self.kind
+        }
+
+        pub fn get_criterion(&self) -> &Option<String> {
+            &self.criterion
+        }
+
+        pub fn get_order(&self) -> &Vec<i64> {
+            &self.order
+        }
+
+        pub fn get_vars(&self) -> &Vec<String> {
+            &self.vars
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct XHeuristic {
+        /// the last-conflict parameter, only for variable heuristics
+        lc: Option<i32>,
+        parts: Vec<XHeuristicPart>,
+    }
+
+    impl XHeuristic {
+        pub fn get_lc(&self) -> &Option<i32> {
+            &self.lc
+        }
+
+        pub fn get_parts(&self) -> &Vec<XHeuristicPart> {
+            &self.parts
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct XFiltering {
+        r#type: String,
+        constraints: Vec<String>,
+    }
+
+    impl XFiltering {
+        pub fn get_type(&self) -> &String {
+            &self.r#type
+        }
+
+        /// the ids of the constraints, empty if it is for all constraints
+        pub fn get_constraints(&self) -> &Vec<String> {
+            &self.constraints
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct XPrepro {
+        consistency: String,
+        constraints: Vec<String>,
+    }
+
+    impl XPrepro {
+        pub fn get_consistency(&self) -> &String {
+            &self.consistency
+        }
+
+        /// the ids of the constraints, empty if it is for all constraints
+        pub fn get_constraints(&self) -> &Vec<String> {
+            &self.constraints
+        }
+    }
+
+    pub struct XAnnotations<'a> {
+        set: &'a XVariableSet,
+        decision: Vec<String>,
+        output: Vec<String>,
+        var_heuristic: Option<XHeuristic>,
+        val_heuristic: Option<XHeuristic>,
+        filtering: Vec<XFiltering>,
+        prepro: Vec<XPrepro>,
+    }
+
+    impl Display for XAnnotations<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = format!(
+                "XAnnotations: decision = {:?} output = {:?}",
+                self.decision, self.output
+            );
+            if let Some(h) = &self.var_heuristic {
+                ret.push_str(&format!(" varHeuristic = {:?}", h));
+            }
+            if let Some(h) = &self.val_heuristic {
+                ret.push_str(&format!(" valHeuristic = {:?}", h));
+            }
+            for e in self.filtering.iter() {
+                ret.push_str(&format!(" filtering = {:?}", e));
+            }
+            for e in self.prepro.iter() {
+                ret.push_str(&format!(" prepro = {:?}", e));
+            }
+            write!(f, "{}", ret)
+        }
+    }
+
+    impl<'a> XAnnotations<'a> {
+        pub fn new(set: &'a XVariableSet) -> Self {
+            Self {
+                set,
+                decision: vec![],
+                output: vec![],
+                var_heuristic: None,
+                val_heuristic: None,
+                filtering: vec![],
+                prepro: vec![],
+            }
+        }
+
+        /// resolve a list of variables, such as "x[] y z[0..2]", into the ids of variables
+        fn resolve(&self, list: &str) -> Result<Vec<String>, Xcsp3Error> {
+            let mut ret: Vec<String> = vec![];
+            for e in list.split_whitespace() {
+                match self.set.construct_scope(&[&e.to_string()]) {
+                    Ok(vec) => {
+                        for (id, _) in vec.into_iter() {
+                            ret.push(id);
+                        }
+                    }
+                    Err(_) => {
+                        return Err(Xcsp3Error::get_annotation_scope_error(&format!(
+                            "the variable {} is not found, ",
+                            e
+                        )));
+                    }
+                }
+            }
+            Ok(ret)
+        }
+
+        pub fn build_decision(&mut self, list: &str) -> Result<(), Xcsp3Error> {
+            self.decision = self.resolve(list)?;
+            Ok(())
+        }
+
+        pub fn build_output(&mut self, list: &str) -> Result<(), Xcsp3Error> {
+            self.output = self.resolve(list)?;
+            Ok(())
+        }
+
+        /// build a part of a heuristic, kind is one of static, random, min and max
+        pub fn build_heuristic_part(
+            &self,
+            kind: &str,
+            criterion: &str,
+            order: &str,
+            list: &str,
+        ) -> Result<XHeuristicPart, Xcsp3Error> {
+            let kind = match kind {
+                "static" => XHeuristicType::Static,
+                "random" => XHeuristicType::Random,
+                "min" => XHeuristicType::Min,
+                "max" => XHeuristicType::Max,
+                _ => {
+                    return Err(Xcsp3Error::get_annotation_heuristic_error(
+                        "unknown heuristic, ",
+                    ))
+                }
+            };
+            let order = match list_to_values(order) {
+                Ok(o) => o,
+                Err(_) => {
+                    return Err(Xcsp3Error::get_annotation_heuristic_error(
+                        "parse the order of the static heuristic error, ",
+                    ))
+                }
+            };
+            Ok(XHeuristicPart {
+                kind,
+                criterion: if criterion.is_empty() {
+                    None
+                } else {
+                    Some(criterion.to_string())
+                },
+                order,
+                vars: self.resolve(list)?,
+            })
+        }
+
+        pub fn build_var_heuristic(
+            &mut self,
+            lc_str: &str,
+            parts: Vec<XHeuristicPart>,
+        ) -> Result<(), Xcsp3Error> {
+            let lc = if lc_str.is_empty() {
+                None
+            } else {
+                match lc_str.parse::<i32>() {
+                    Ok(n) => Some(n),
+                    Err(_) => {
+                        return Err(Xcsp3Error::get_annotation_heuristic_error(
+                            "parse the lc of varHeuristic error, ",
+                        ))
+                    }
+                }
+            };
+            self.var_heuristic = Some(XHeuristic { lc, parts });
+            Ok(())
+        }
+
+        pub fn build_val_heuristic(&mut self, parts: Vec<XHeuristicPart>) {
+            self.val_heuristic = Some(XHeuristic { lc: None, parts });
+        }
+
+        pub fn build_filtering(&mut self, type_str: &str, constraints: &str) {
+            self.filtering.push(XFiltering {
+                r#type: type_str.to_string(),
+                constraints: constraints.split_whitespace().map(String::from).collect(),
+            })
+        }
+
+        pub fn build_prepro(&mut self, consistency: &str, constraints: &str) {
+            self.prepro.push(XPrepro {
+                consistency: consistency.to_string(),
+                constraints: constraints.split_whitespace().map(String::from).collect(),
+            })
+        }
+
+        /// the variables to branch on, empty if there is no decision annotation
+        pub fn get_decision(&self) -> &Vec<String> {
+            &self.decision
+        }
+
+        /// the variables to show in solutions, empty if there is no output annotation
+        pub fn get_output(&self) -> &Vec<String> {
+            &self.output
+        }
+
+        pub fn get_var_heuristic(&self) -> &Option<XHeuristic> {
+            &self.var_heuristic
+        }
+
+        pub fn get_val_heuristic(&self) -> &Option<XHeuristic> {
+            &self.val_heuristic
+        }
+
+        pub fn get_filtering(&self) -> &Vec<XFiltering> {
+            &self.filtering
+        }
+
+        pub fn get_prepro(&self) -> &Vec<XPrepro> {
+            &self.prepro
+        }
+    }
+}
@@ -49,6 +49,7 @@ pub mod xextension;
 pub mod xinstantiation;
 pub mod xmdd;
 pub mod xordered;
+pub mod xprimitive;
 pub mod xregular;
 
 pub mod xconstraint_trait;
@@ -58,12 +59,25 @@ pub mod xintension;
 pub mod xmax_min;
 pub mod xsum;
 
+pub mod xall_distant;
+pub mod xbalance;
+pub mod xbin_packing;
 pub mod xcardinality;
 pub mod xchannel;
+pub mod xcircuit;
+pub mod xclause;
 pub mod xcount;
 pub mod xcumulative;
+pub mod xflow;
+pub mod xknapsack;
+pub mod xlex;
+pub mod xlex_matrix;
+pub mod xmeta_constraint;
 pub mod xn_values;
 pub mod xno_overlap;
 pub mod xno_overlap_k_dimensional;
+pub mod xprecedence;
 pub mod xslide;
+pub mod xsoft_constraint;
+pub mod xspread_deviation;
 pub mod xstretch;
@@ -40,6 +40,7 @@
 
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
 
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
@@ -55,6 +56,7 @@ pub mod xcsp3_core {
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
         // scope_vec_var: Vec<(String, &'a XDomainInteger)>,
+        reification: Option<XReification>,
     }
 
     impl Display for XAllDifferent<'_> {
@@ -95,6 +97,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XAllDifferent<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str_vec(scope_vec_str: Vec<XVarVal>, set: &'a XVariableSet) -> Self {
             XAllDifferent::new(scope_vec_str, set)
         }
@@ -107,6 +117,7 @@ pub mod xcsp3_core {
         }
         pub fn new(scope: Vec<XVarVal>, set: &'a XVariableSet) -> Self {
             XAllDifferent {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use std::collections::HashMap;
     use std::fmt::{Display, Formatter};
@@ -57,6 +58,7 @@ pub mod xcsp3_core {
         scope: Vec<XVarVal>,
         set: &'a XVariableSet,
         except: Vec<XVarVal>,
+        reification: Option<XReification>,
     }
 
     impl Display for XAllDifferentExcept<'_> {
@@ -104,6 +106,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XAllDifferentExcept<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             except_str: &str,
@@ -120,6 +130,7 @@ pub mod xcsp3_core {
 
         pub fn new(scope: Vec<XVarVal>, set: &'a XVariableSet, except: Vec<XVarVal>) -> Self {
             XAllDifferentExcept {
+                reification: None,
                 map: Default::default(),
                 scope,
                 except,
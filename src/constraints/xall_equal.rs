@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use std::collections::HashMap;
     use std::fmt::{Display, Formatter};
@@ -54,6 +55,7 @@ pub mod xcsp3_core {
         scope: Vec<XVarVal>,
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
+        reification: Option<XReification>,
     }
 
     impl Display for XAllEqual<'_> {
@@ -94,6 +96,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XAllEqual<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(list: &str, set: &'a XVariableSet) -> Result<Self, Xcsp3Error> {
             match list_to_vec_var_val(list) {
                 Ok(scope_vec_str) => Ok(XAllEqual::new(scope_vec_str, set)),
@@ -102,6 +112,7 @@ pub mod xcsp3_core {
         }
         pub fn new(scope: Vec<XVarVal>, set: &'a XVariableSet) -> Self {
             XAllEqual {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
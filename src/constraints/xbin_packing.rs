@@ -0,0 +1,244 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 12:20
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::data_structs::xrelational_operand::xcsp3_core::Operand;
+    use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::list_to_vec_var_val;
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    /// exactly one of condition, limits and loads is given.
+    /// like the other constraints, the scope is the list, the variables of the limits, the loads
+    /// and the operand of the condition are given by their getters.
+    pub struct XBinPacking<'a> {
+        scope: Vec<XVarVal>,
+        map: HashMap<String, &'a XDomainInteger>,
+        set: &'a XVariableSet,
+        sizes: Vec<XVarVal>,
+        operator: Option<Operator>,
+        operand: Option<Operand>,
+        limits: Option<Vec<XVarVal>>,
+        loads: Option<Vec<XVarVal>>,
+        reification: Option<XReification>,
+    }
+
+    impl Display for XBinPacking<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for e in self.scope.iter() {
+                ret.push('(');
+                ret.push_str(&e.to_string());
+                ret.push_str("), ")
+            }
+            ret.push_str("sizes = (");
+            for e in self.sizes.iter() {
+                ret.push_str(&e.to_string());
+                ret.push_str(", ")
+            }
+            ret.push_str("), ");
+            if let (Some(operator), Some(operand)) = (&self.operator, &self.operand) {
+                ret.push_str(&format!("condition = ({:?},{:?})", operator, operand));
+            }
+            if let Some(limits) = &self.limits {
+                ret.push_str("limits = (");
+                for e in limits.iter() {
+                    ret.push_str(&e.to_string());
+                    ret.push_str(", ")
+                }
+                ret.push(')');
+            }
+            if let Some(loads) = &self.loads {
+                ret.push_str("loads = (");
+                for e in loads.iter() {
+                    ret.push_str(&e.to_string());
+                    ret.push_str(", ")
+                }
+                ret.push(')');
+            }
+            write!(f, "XBinPacking: list =  {}", ret)
+        }
+    }
+
+    impl XConstraintTrait for XBinPacking<'_> {
+        fn get_scope_string(&self) -> &Vec<XVarVal> {
+            &self.scope
+        }
+
+        fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
+            for e in &self.scope {
+                if let XVarVal::IntVar(s) = e {
+                    if !self.map.contains_key(s) {
+                        if let Ok(vec) = self.set.construct_scope(&[s]) {
+                            for (vs, vv) in vec.into_iter() {
+                                self.map.insert(vs, vv);
+                            }
+                        }
+                    }
+                }
+            }
+            let mut scope_vec_var: Vec<(&String, &XDomainInteger)> = vec![];
+            for e in self.map.iter() {
+                scope_vec_var.push((e.0, e.1))
+            }
+            scope_vec_var
+        }
+    }
+
+    impl<'a> XBinPacking<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        pub fn from_str(
+            list: &str,
+            sizes_str: &str,
+            condition: &str,
+            limits_str: &str,
+            loads_str: &str,
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let scope = list_to_vec_var_val(list)?;
+            let sizes = list_to_vec_var_val(sizes_str)?;
+            let given = [condition, limits_str, loads_str]
+                .iter()
+                .filter(|s| !s.trim().is_empty())
+                .count();
+            if given != 1 {
+                return Err(Xcsp3Error::get_constraint_bin_packing_error(
+                    "parse binPacking constraint error, exactly one of condition, limits and loads is required, ",
+                ));
+            }
+            let mut operator = None;
+            let mut operand = None;
+            let mut limits = None;
+            let mut loads = None;
+            if !condition.trim().is_empty() {
+                let condition = condition.replace(['(', ')', ','], " ");
+                let spilt: Vec<&str> = condition.split_whitespace().collect();
+
+                let ope: Operator = match Operator::get_operator_by_str(spilt[0]) {
+                    None => {
+                        return Err(Xcsp3Error::get_constraint_bin_packing_error(
+                            "parse binPacking constraint Operator error, ",
+                        ));
+                    }
+                    Some(o) => o,
+                };
+
+                let rand: Operand = match Operand::get_operand_by_str(&spilt[1..], &ope) {
+                    None => {
+                        return Err(Xcsp3Error::get_constraint_bin_packing_error(
+                            "parse binPacking constraint Operand error, ",
+                        ));
+                    }
+                    Some(r) => r,
+                };
+                operator = Some(ope);
+                operand = Some(rand);
+            } else if !limits_str.trim().is_empty() {
+                match list_to_vec_var_val(limits_str) {
+                    Ok(l) => limits = Some(l),
+                    Err(e) => return Err(e),
+                }
+            } else {
+                match list_to_vec_var_val(loads_str) {
+                    Ok(l) => loads = Some(l),
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(Self::new(
+                scope, set, sizes, operator, operand, limits, loads,
+            ))
+        }
+
+        pub fn new(
+            scope: Vec<XVarVal>,
+            set: &'a XVariableSet,
+            sizes: Vec<XVarVal>,
+            operator: Option<Operator>,
+            operand: Option<Operand>,
+            limits: Option<Vec<XVarVal>>,
+            loads: Option<Vec<XVarVal>>,
+        ) -> Self {
+            Self {
+                reification: None,
+                scope,
+                map: Default::default(),
+                set,
+                sizes,
+                operator,
+                operand,
+                limits,
+                loads,
+            }
+        }
+
+        pub fn get_sizes(&self) -> &Vec<XVarVal> {
+            &self.sizes
+        }
+
+        pub fn get_operator(&self) -> &Option<Operator> {
+            &self.operator
+        }
+
+        pub fn get_operand(&self) -> &Option<Operand> {
+            &self.operand
+        }
+
+        pub fn get_limits(&self) -> &Option<Vec<XVarVal>> {
+            &self.limits
+        }
+
+        pub fn get_loads(&self) -> &Option<Vec<XVarVal>> {
+            &self.loads
+        }
+    }
+}
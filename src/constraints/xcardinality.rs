@@ -40,6 +40,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::utils::utils_functions::xcsp3_utils::list_to_vec_var_val;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
@@ -54,6 +55,7 @@ pub mod xcsp3_core {
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
         closed: Option<bool>,
+        reification: Option<XReification>,
     }
     impl Display for XCardinality<'_> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -84,6 +86,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XCardinality<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             values_str: &str,
@@ -126,6 +136,7 @@ pub mod xcsp3_core {
             closed: Option<bool>,
         ) -> Self {
             Self {
+                reification: None,
                 scope,
                 values,
                 occurs,
@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::utils::utils_functions::xcsp3_utils::list_to_vec_var_val;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
@@ -54,6 +55,7 @@ pub mod xcsp3_core {
         set: &'a XVariableSet,
         start_index: Option<i32>,
         value: Option<XVarVal>,
+        reification: Option<XReification>,
     }
 
     impl XConstraintTrait for XChannel<'_> {
@@ -82,6 +84,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XChannel<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn get_start_index(&self) -> &Option<i32> {
             &self.start_index
         }
@@ -134,6 +144,7 @@ pub mod xcsp3_core {
             value: Option<XVarVal>,
         ) -> Self {
             Self {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
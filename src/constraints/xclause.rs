@@ -0,0 +1,175 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 13:05
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    /// the literals of a clause, each variable is resolved and must be a 0/1 variable.
+    pub struct XClause<'a> {
+        scope: Vec<XVarVal>,
+        map: HashMap<String, &'a XDomainInteger>,
+        positive: Vec<(String, &'a XDomainInteger)>,
+        negative: Vec<(String, &'a XDomainInteger)>,
+        reification: Option<XReification>,
+    }
+
+    impl Display for XClause<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for e in self.positive.iter() {
+                ret.push('(');
+                ret.push_str(&e.0);
+                ret.push_str("), ")
+            }
+            ret.push_str("negative = ");
+            for e in self.negative.iter() {
+                ret.push('(');
+                ret.push_str(&e.0);
+                ret.push_str("), ")
+            }
+            write!(f, "XClause: positive =  {}", ret)
+        }
+    }
+
+    impl XConstraintTrait for XClause<'_> {
+        fn get_scope_string(&self) -> &Vec<XVarVal> {
+            &self.scope
+        }
+
+        fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
+            if self.map.is_empty() {
+                for (vs, vv) in self.positive.iter().chain(self.negative.iter()) {
+                    self.map.insert(vs.clone(), vv);
+                }
+            }
+            let mut scope_vec_var: Vec<(&String, &XDomainInteger)> = vec![];
+            for e in self.map.iter() {
+                scope_vec_var.push((e.0, e.1))
+            }
+            scope_vec_var
+        }
+    }
+
+    impl<'a> XClause<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        /// the literals are separated by whitespace, a negative literal is written as not(x).
+        pub fn from_str(list: &str, set: &'a XVariableSet) -> Result<Self, Xcsp3Error> {
+            let mut scope: Vec<XVarVal> = vec![];
+            let mut positive: Vec<(String, &'a XDomainInteger)> = vec![];
+            let mut negative: Vec<(String, &'a XDomainInteger)> = vec![];
+            for literal in list.split_whitespace() {
+                let mut var = literal;
+                let mut is_negative = false;
+                while let Some(inner) = var.strip_prefix("not(") {
+                    match inner.strip_suffix(')') {
+                        None => {
+                            return Err(Xcsp3Error::get_constraint_clause_error(
+                                "parse clause constraint literal error, ",
+                            ));
+                        }
+                        Some(v) => var = v,
+                    }
+                    is_negative = !is_negative;
+                }
+                let var_val = match XVarVal::from_string(var) {
+                    Some(XVarVal::IntVar(v)) => XVarVal::IntVar(v),
+                    _ => {
+                        return Err(Xcsp3Error::get_constraint_clause_error(
+                            "parse clause constraint literal error, a literal must be a variable, ",
+                        ));
+                    }
+                };
+                let var = var.to_string();
+                let vars = set.construct_scope(&[&var])?;
+                for (vs, vv) in vars.into_iter() {
+                    if vv.minimum().map_or(true, |m| m < 0) || vv.maximum().map_or(true, |m| m > 1)
+                    {
+                        return Err(Xcsp3Error::get_constraint_clause_error(
+                            "parse clause constraint error, a literal must be a 0/1 variable, ",
+                        ));
+                    }
+                    if is_negative {
+                        negative.push((vs, vv));
+                    } else {
+                        positive.push((vs, vv));
+                    }
+                }
+                scope.push(var_val);
+            }
+            Ok(Self::new(scope, positive, negative))
+        }
+
+        pub fn new(
+            scope: Vec<XVarVal>,
+            positive: Vec<(String, &'a XDomainInteger)>,
+            negative: Vec<(String, &'a XDomainInteger)>,
+        ) -> Self {
+            Self {
+                reification: None,
+                scope,
+                map: Default::default(),
+                positive,
+                negative,
+            }
+        }
+
+        pub fn get_positive(&self) -> &Vec<(String, &'a XDomainInteger)> {
+            &self.positive
+        }
+
+        pub fn get_negative(&self) -> &Vec<(String, &'a XDomainInteger)> {
+            &self.negative
+        }
+    }
+}
@@ -46,23 +46,38 @@ pub mod xcsp3_core {
     use crate::constraints::xgroup::xcsp3_core::XGroup;
     use crate::constraints::xinstantiation::xcsp3_core::XInstantiation;
 
+    use crate::constraints::xall_distant::xcsp3_core::XAllDistant;
+    use crate::constraints::xbalance::xcsp3_core::XBalance;
+    use crate::constraints::xbin_packing::xcsp3_core::XBinPacking;
     use crate::constraints::xcardinality::xcsp3_core::XCardinality;
     use crate::constraints::xchannel::xcsp3_core::XChannel;
+    use crate::constraints::xcircuit::xcsp3_core::XCircuit;
+    use crate::constraints::xclause::xcsp3_core::XClause;
     use crate::constraints::xcount::xcsp3_core::XCount;
     use crate::constraints::xcumulative::xcsp3_core::XCumulative;
+    use crate::constraints::xflow::xcsp3_core::XFlow;
     use crate::constraints::xintension::xcsp3_core::XIntention;
+    use crate::constraints::xknapsack::xcsp3_core::XKnapsack;
+    use crate::constraints::xlex::xcsp3_core::XLex;
+    use crate::constraints::xlex_matrix::xcsp3_core::XLexMatrix;
     use crate::constraints::xmax_min::xcsp3_core::XMaxMin;
     use crate::constraints::xmdd::xcsp3_core::XMdd;
+    use crate::constraints::xmeta_constraint::xcsp3_core::{XMetaConstraint, XMetaType};
     use crate::constraints::xn_values::xcsp3_core::XNValues;
     use crate::constraints::xno_overlap::xcsp3_core::XNoOverlap;
     use crate::constraints::xno_overlap_k_dimensional::xcsp3_core::XNoOverlapKDim;
     use crate::constraints::xordered::xcsp3_core::XOrdered;
+    use crate::constraints::xprecedence::xcsp3_core::XPrecedence;
     use crate::constraints::xregular::xcsp3_core::XRegular;
     use crate::constraints::xslide::xcsp3_core::XSlide;
+    use crate::constraints::xsoft_constraint::xcsp3_core::XSoftConstraint;
+    use crate::constraints::xspread_deviation::xcsp3_core::XSpreadDeviation;
     use crate::constraints::xstretch::xcsp3_core::XStretch;
     use crate::constraints::xsum::xcsp3_core::XSum;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
-    use crate::utils::utils_functions::xcsp3_utils::list_to_matrix_ids;
+    use crate::utils::utils_functions::xcsp3_utils::{
+        list_to_matrix_ids, list_to_vec_var_val, tuple_with_costs_to_vector,
+    };
     use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
     use std::slice::{Iter, IterMut};
 
@@ -139,6 +154,130 @@ pub mod xcsp3_core {
                 Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
             }
         }
+        pub fn build_circuit(&mut self, list: &str, start_index_str: &str, size_str: &str) {
+            match XCircuit::from_str(list, start_index_str, size_str, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XCircuit(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_lex(&mut self, lists: &[String], operator: &str) {
+            match XLex::from_str(lists, operator, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XLex(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_lex_matrix(&mut self, matrix: &str, operator: &str) {
+            match XLexMatrix::from_str(matrix, operator, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XLexMatrix(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_precedence(&mut self, list: &str, values_str: &str, covered_str: &str) {
+            match XPrecedence::from_str(list, values_str, covered_str, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XPrecedence(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_bin_packing(
+            &mut self,
+            list: &str,
+            sizes_str: &str,
+            condition: &str,
+            limits_str: &str,
+            loads_str: &str,
+        ) {
+            match XBinPacking::from_str(list, sizes_str, condition, limits_str, loads_str, self.set)
+            {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XBinPacking(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_clause(&mut self, list: &str) {
+            match XClause::from_str(list, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XClause(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_all_distant(&mut self, list: &str, condition: &str) {
+            match XAllDistant::from_str(list, condition, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XAllDistant(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_balance(&mut self, list: &str, values_str: &str, condition: &str) {
+            match XBalance::from_str(list, values_str, condition, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XBalance(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_spread(&mut self, list: &str, total_str: &str, condition: &str) {
+            match XSpreadDeviation::from_str(list, total_str, condition, true, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XSpread(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_deviation(&mut self, list: &str, total_str: &str, condition: &str) {
+            match XSpreadDeviation::from_str(list, total_str, condition, false, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XDeviation(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_knapsack(
+            &mut self,
+            list: &str,
+            weights_str: &str,
+            profits_str: &str,
+            conditions: &[String],
+        ) {
+            match XKnapsack::from_str(list, weights_str, profits_str, conditions, self.set) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XKnapsack(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+        pub fn build_flow(
+            &mut self,
+            list: &str,
+            balance_str: &str,
+            arcs_str: &str,
+            weights_str: &str,
+            condition: &str,
+        ) {
+            match XFlow::from_str(
+                list,
+                balance_str,
+                arcs_str,
+                weights_str,
+                condition,
+                self.set,
+            ) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XFlow(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
         pub fn build_cardinality(
             &mut self,
             list: &str,
@@ -197,6 +336,18 @@ pub mod xcsp3_core {
                 Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
             }
         }
+        pub fn build_meta_constraint(
+            &mut self,
+            meta_type: XMetaType,
+            children: Vec<XConstraintType<'a>>,
+        ) {
+            match XMetaConstraint::from_children(meta_type, children) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XMetaConstraint(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
         pub fn build_group(&mut self, cc: XConstraintType<'a>, args: &[String]) {
             // if let XConstraintType::XGroup(_) = &cc {
             //     // println!("group is in {}",c.to_string());
@@ -217,6 +368,11 @@ pub mod xcsp3_core {
             self.constraints.pop()
         }
 
+        /// remove and return the constraints built from the index start
+        pub fn drain_from(&mut self, start: usize) -> Vec<XConstraintType<'a>> {
+            self.constraints.drain(start..).collect()
+        }
+
         pub fn iter(&self) -> Iter<'_, XConstraintType> {
             self.constraints.iter()
         }
@@ -338,6 +494,55 @@ pub mod xcsp3_core {
             }
         }
 
+        /// build a soft extension, eg. the tuples are (0,1):5 (1,1):3
+        pub fn build_soft_extension(
+            &mut self,
+            list: &str,
+            tuple: &str,
+            default_cost: &str,
+            ub: &str,
+        ) {
+            let extension = match list_to_vec_var_val(list) {
+                Ok(scope) => match tuple_with_costs_to_vector(tuple) {
+                    // the extension forbids no tuple, the costed tuples are kept by the soft constraint
+                    Ok((tuples, costs)) => Ok((
+                        XExtension::new(scope, self.set, vec![], false),
+                        tuples,
+                        costs,
+                    )),
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            };
+            match extension.and_then(|(c, tuples, costs)| {
+                XSoftConstraint::from_tuples(
+                    XConstraintType::XExtension(c),
+                    default_cost,
+                    tuples,
+                    costs,
+                    ub,
+                )
+            }) {
+                Ok(c) => {
+                    self.constraints.push(XConstraintType::XSoftConstraint(c));
+                }
+                Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+            }
+        }
+
+        /// relax the constraints built from the index start, which are wrapped into soft constraints
+        pub fn build_soft(&mut self, start: usize, measure: &str, violation_cost: &str, ub: &str) {
+            let relaxed: Vec<XConstraintType<'a>> = self.constraints.drain(start..).collect();
+            for c in relaxed.into_iter() {
+                match XSoftConstraint::from_violation(c, measure, violation_cost, ub) {
+                    Ok(c) => {
+                        self.constraints.push(XConstraintType::XSoftConstraint(c));
+                    }
+                    Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
+                }
+            }
+        }
+
         pub fn build_all_equal(&mut self, list: &str) {
             match XAllEqual::from_str(list, self.set) {
                 Err(e) => self.constraints.push(XConstraintType::XConstraintNone(e)),
@@ -41,26 +41,40 @@
 pub mod xcsp3_core {
     use crate::constraints::xall_different::xcsp3_core::XAllDifferent;
     use crate::constraints::xall_different_except::xcsp3_core::XAllDifferentExcept;
+    use crate::constraints::xall_distant::xcsp3_core::XAllDistant;
     use crate::constraints::xall_equal::xcsp3_core::XAllEqual;
+    use crate::constraints::xbalance::xcsp3_core::XBalance;
+    use crate::constraints::xbin_packing::xcsp3_core::XBinPacking;
     use crate::constraints::xcardinality::xcsp3_core::XCardinality;
     use crate::constraints::xchannel::xcsp3_core::XChannel;
+    use crate::constraints::xcircuit::xcsp3_core::XCircuit;
+    use crate::constraints::xclause::xcsp3_core::XClause;
     use crate::constraints::xcount::xcsp3_core::XCount;
     use crate::constraints::xcumulative::xcsp3_core::XCumulative;
     use crate::constraints::xelement::xcsp3_core::XElement;
     use crate::constraints::xextension::xcsp3_core::XExtension;
+    use crate::constraints::xflow::xcsp3_core::XFlow;
     use crate::constraints::xgroup::xcsp3_core::XGroup;
     use crate::constraints::xinstantiation::xcsp3_core::XInstantiation;
     use crate::constraints::xintension::xcsp3_core::XIntention;
+    use crate::constraints::xknapsack::xcsp3_core::XKnapsack;
+    use crate::constraints::xlex::xcsp3_core::XLex;
+    use crate::constraints::xlex_matrix::xcsp3_core::XLexMatrix;
     use crate::constraints::xmax_min::xcsp3_core::XMaxMin;
     use crate::constraints::xmdd::xcsp3_core::XMdd;
+    use crate::constraints::xmeta_constraint::xcsp3_core::XMetaConstraint;
     use crate::constraints::xn_values::xcsp3_core::XNValues;
     use crate::constraints::xno_overlap::xcsp3_core::XNoOverlap;
     use crate::constraints::xno_overlap_k_dimensional::xcsp3_core::XNoOverlapKDim;
     use crate::constraints::xordered::xcsp3_core::XOrdered;
+    use crate::constraints::xprecedence::xcsp3_core::XPrecedence;
     use crate::constraints::xregular::xcsp3_core::XRegular;
     use crate::constraints::xslide::xcsp3_core::XSlide;
+    use crate::constraints::xsoft_constraint::xcsp3_core::XSoftConstraint;
+    use crate::constraints::xspread_deviation::xcsp3_core::XSpreadDeviation;
     use crate::constraints::xstretch::xcsp3_core::XStretch;
     use crate::constraints::xsum::xcsp3_core::XSum;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use std::fmt::{Display, Formatter};
 
@@ -90,11 +104,119 @@ pub mod xcsp3_core {
         XNoOverlap(XNoOverlap<'a>),
         XStretch(XStretch<'a>),
         XNoOverlapKDim(XNoOverlapKDim<'a>),
+        XCircuit(XCircuit<'a>),
+        XLex(XLex<'a>),
+        XLexMatrix(XLexMatrix<'a>),
+        XPrecedence(XPrecedence<'a>),
+        XBinPacking(XBinPacking<'a>),
+        XClause(XClause<'a>),
+        XAllDistant(XAllDistant<'a>),
+        XBalance(XBalance<'a>),
+        XSpread(XSpreadDeviation<'a>),
+        XDeviation(XSpreadDeviation<'a>),
+        XKnapsack(XKnapsack<'a>),
+        XFlow(XFlow<'a>),
+        XMetaConstraint(XMetaConstraint<'a>),
+        XSoftConstraint(XSoftConstraint<'a>),
+    }
+
+    impl XConstraintType<'_> {
+        /// the reification of this constraint, the one of a group is given by its template
+        pub fn reification(&self) -> Option<&XReification> {
+            match self {
+                XConstraintType::XConstraintNone(_) => None,
+                XConstraintType::XExtension(c) => c.get_reification().as_ref(),
+                XConstraintType::XAllDifferent(c) => c.get_reification().as_ref(),
+                XConstraintType::XAllDifferentExcept(c) => c.get_reification().as_ref(),
+                XConstraintType::XInstantiation(c) => c.get_reification().as_ref(),
+                XConstraintType::XAllEqual(c) => c.get_reification().as_ref(),
+                XConstraintType::XOrdered(c) => c.get_reification().as_ref(),
+                XConstraintType::XRegular(c) => c.get_reification().as_ref(),
+                XConstraintType::XMdd(c) => c.get_reification().as_ref(),
+                XConstraintType::XIntention(c) => c.get_reification().as_ref(),
+                XConstraintType::XGroup(c) => c.get_template().reification(),
+                XConstraintType::XSum(c) => c.get_reification().as_ref(),
+                XConstraintType::XMaximum(c) => c.get_reification().as_ref(),
+                XConstraintType::XMinimum(c) => c.get_reification().as_ref(),
+                XConstraintType::XElement(c) => c.get_reification().as_ref(),
+                XConstraintType::XSlide(c) => c.get_reification().as_ref(),
+                XConstraintType::XCount(c) => c.get_reification().as_ref(),
+                XConstraintType::XNValues(c) => c.get_reification().as_ref(),
+                XConstraintType::XCardinality(c) => c.get_reification().as_ref(),
+                XConstraintType::XChannel(c) => c.get_reification().as_ref(),
+                XConstraintType::XCumulative(c) => c.get_reification().as_ref(),
+                XConstraintType::XNoOverlap(c) => c.get_reification().as_ref(),
+                XConstraintType::XStretch(c) => c.get_reification().as_ref(),
+                XConstraintType::XNoOverlapKDim(c) => c.get_reification().as_ref(),
+                XConstraintType::XCircuit(c) => c.get_reification().as_ref(),
+                XConstraintType::XLex(c) => c.get_reification().as_ref(),
+                XConstraintType::XLexMatrix(c) => c.get_reification().as_ref(),
+                XConstraintType::XPrecedence(c) => c.get_reification().as_ref(),
+                XConstraintType::XBinPacking(c) => c.get_reification().as_ref(),
+                XConstraintType::XClause(c) => c.get_reification().as_ref(),
+                XConstraintType::XAllDistant(c) => c.get_reification().as_ref(),
+                XConstraintType::XBalance(c) => c.get_reification().as_ref(),
+                XConstraintType::XSpread(c) => c.get_reification().as_ref(),
+                XConstraintType::XDeviation(c) => c.get_reification().as_ref(),
+                XConstraintType::XKnapsack(c) => c.get_reification().as_ref(),
+                XConstraintType::XFlow(c) => c.get_reification().as_ref(),
+                XConstraintType::XMetaConstraint(c) => c.get_reification().as_ref(),
+                XConstraintType::XSoftConstraint(c) => c.get_reification().as_ref(),
+            }
+        }
+
+        pub(crate) fn set_reification(&mut self, reification: Option<XReification>) {
+            match self {
+                XConstraintType::XConstraintNone(_) => {}
+                XConstraintType::XExtension(c) => c.set_reification(reification),
+                XConstraintType::XAllDifferent(c) => c.set_reification(reification),
+                XConstraintType::XAllDifferentExcept(c) => c.set_reification(reification),
+                XConstraintType::XInstantiation(c) => c.set_reification(reification),
+                XConstraintType::XAllEqual(c) => c.set_reification(reification),
+                XConstraintType::XOrdered(c) => c.set_reification(reification),
+                XConstraintType::XRegular(c) => c.set_reification(reification),
+                XConstraintType::XMdd(c) => c.set_reification(reification),
+                XConstraintType::XIntention(c) => c.set_reification(reification),
+                XConstraintType::XGroup(_) => {}
+                XConstraintType::XSum(c) => c.set_reification(reification),
+                XConstraintType::XMaximum(c) => c.set_reification(reification),
+                XConstraintType::XMinimum(c) => c.set_reification(reification),
+                XConstraintType::XElement(c) => c.set_reification(reification),
+                XConstraintType::XSlide(c) => c.set_reification(reification),
+                XConstraintType::XCount(c) => c.set_reification(reification),
+                XConstraintType::XNValues(c) => c.set_reification(reification),
+                XConstraintType::XCardinality(c) => c.set_reification(reification),
+                XConstraintType::XChannel(c) => c.set_reification(reification),
+                XConstraintType::XCumulative(c) => c.set_reification(reification),
+                XConstraintType::XNoOverlap(c) => c.set_reification(reification),
+                XConstraintType::XStretch(c) => c.set_reification(reification),
+                XConstraintType::XNoOverlapKDim(c) => c.set_reification(reification),
+                XConstraintType::XCircuit(c) => c.set_reification(reification),
+                XConstraintType::XLex(c) => c.set_reification(reification),
+                XConstraintType::XLexMatrix(c) => c.set_reification(reification),
+                XConstraintType::XPrecedence(c) => c.set_reification(reification),
+                XConstraintType::XBinPacking(c) => c.set_reification(reification),
+                XConstraintType::XClause(c) => c.set_reification(reification),
+                XConstraintType::XAllDistant(c) => c.set_reification(reification),
+                XConstraintType::XBalance(c) => c.set_reification(reification),
+                XConstraintType::XSpread(c) => c.set_reification(reification),
+                XConstraintType::XDeviation(c) => c.set_reification(reification),
+                XConstraintType::XKnapsack(c) => c.set_reification(reification),
+                XConstraintType::XFlow(c) => c.set_reification(reification),
+                XConstraintType::XMetaConstraint(c) => c.set_reification(reification),
+                XConstraintType::XSoftConstraint(c) => c.set_reification(reification),
+            }
+        }
     }
 
     impl Display for XConstraintType<'_> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            write!(f,"{}",
+            // the template of a group shows its own reification
+            let reification = match (self, self.reification()) {
+                (XConstraintType::XGroup(_), _) | (_, None) => String::default(),
+                (_, Some(r)) => format!(" reification = {}", r),
+            };
+            write!(f,"{}{}",
                     match self {
                         XConstraintType::XConstraintNone(e) => format!(
                             "XConstraintNone: there must be an error when parse this constraint. Error is {}",e
@@ -122,7 +244,22 @@ pub mod xcsp3_core {
                         XConstraintType::XNValues(c) => c.to_string(),
                         XConstraintType::XCardinality(c) => c.to_string(),
                         XConstraintType::XNoOverlapKDim(c) => c.to_string(),
-                    }
+                        XConstraintType::XCircuit(c) => c.to_string(),
+                        XConstraintType::XLex(c) => c.to_string(),
+                        XConstraintType::XLexMatrix(c) => c.to_string(),
+                        XConstraintType::XPrecedence(c) => c.to_string(),
+                        XConstraintType::XBinPacking(c) => c.to_string(),
+                        XConstraintType::XClause(c) => c.to_string(),
+                        XConstraintType::XAllDistant(c) => c.to_string(),
+                        XConstraintType::XBalance(c) => c.to_string(),
+                        XConstraintType::XSpread(c) => c.to_string(),
+                        XConstraintType::XDeviation(c) => c.to_string(),
+                        XConstraintType::XKnapsack(c) => c.to_string(),
+                        XConstraintType::XFlow(c) => c.to_string(),
+                        XConstraintType::XMetaConstraint(c) => c.to_string(),
+                        XConstraintType::XSoftConstraint(c) => c.to_string(),
+                    },
+                    reification
              )
         }
     }
@@ -40,6 +40,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::data_structs::xrelational_operand::xcsp3_core::Operand;
     use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
@@ -61,6 +62,7 @@ pub mod xcsp3_core {
         operator: Operator,
         operand: Operand,
         star_index: Option<i32>,
+        reification: Option<XReification>,
     }
 
     impl Display for XCumulative<'_> {
@@ -112,6 +114,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XCumulative<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             origins_str: &str,
             lengths_str: &str,
@@ -217,6 +227,7 @@ pub mod xcsp3_core {
             star_index: Option<i32>,
         ) -> Self {
             Self {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
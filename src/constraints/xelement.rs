@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::utils::utils_functions::xcsp3_utils::list_to_vec_var_val;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
@@ -56,9 +57,18 @@ pub mod xcsp3_core {
         value: XVarVal,
         index: XVarVal,
         start_index: Option<i32>,
+        reification: Option<XReification>,
     }
 
     impl<'a> XElement<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             value_str: &str,
@@ -117,6 +127,7 @@ pub mod xcsp3_core {
             start_index: Option<i32>,
         ) -> Self {
             Self {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
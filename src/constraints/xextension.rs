@@ -40,7 +40,10 @@
 
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
-    use crate::utils::utils_functions::xcsp3_utils::{list_to_vec_var_val, tuple_to_vector};
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::utils::utils_functions::xcsp3_utils::{
+        list_to_vec_var_val, symbolic_tuple_to_vector, tuple_to_vector, XTuple,
+    };
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
     use std::collections::HashMap;
     use std::fmt::{Display, Formatter};
@@ -55,9 +58,10 @@ pub mod xcsp3_core {
         scope: Vec<XVarVal>,
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
-        ///if the  value in tuples is i32::MAX, then it is the star
-        tuples: Vec<Vec<i32>>,
+        /// the star * is None
+        tuples: Vec<XTuple>,
         is_support: bool,
+        reification: Option<XReification>,
     }
 
     impl Display for XExtension<'_> {
@@ -77,7 +81,22 @@ pub mod xcsp3_core {
             //     "tuples = {:?}, is_support = {}",
             //     self.tuples, self.is_support
             // ));
-            write!(f, "XExtension: list = {}{:?}", ret, self.tuples)
+            ret.push('[');
+            for t in self.tuples.iter() {
+                ret.push('(');
+                for (j, e) in t.iter().enumerate() {
+                    match e {
+                        Some(v) => ret.push_str(&v.to_string()),
+                        None => ret.push('*'),
+                    }
+                    if j != t.len() - 1 {
+                        ret.push(',');
+                    }
+                }
+                ret.push(')');
+            }
+            ret.push(']');
+            write!(f, "XExtension: list = {}", ret)
         }
     }
 
@@ -107,7 +126,16 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XExtension<'a> {
-        /// construct the constraint from two strings and a bool
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        /// construct the constraint from two strings and a bool,
+        /// the symbols in tuples are mapped to values by the domains of the list
         pub fn from_str(
             list: &str,
             tuple: &str,
@@ -115,6 +143,9 @@ pub mod xcsp3_core {
             set: &'a XVariableSet,
         ) -> Result<Self, Xcsp3Error> {
             // let tt= TimeInterval::new();
+            if tuple.contains(|c: char| c.is_ascii_alphabetic()) {
+                return Self::from_symbolic_str(list, tuple, is_support, set);
+            }
             let a = match list_to_vec_var_val(list) {
                 Ok(scope_vec_str) => match tuple_to_vector(tuple, !tuple.contains('(')) {
                     Ok(tuples) => {
@@ -129,13 +160,32 @@ pub mod xcsp3_core {
             a
         }
 
+        fn from_symbolic_str(
+            list: &str,
+            tuple: &str,
+            is_support: bool,
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let scope_vec_str = list_to_vec_var_val(list)?;
+            let ids: Vec<String> = scope_vec_str.iter().map(|e| e.to_string()).collect();
+            let ids: Vec<&String> = ids.iter().collect();
+            let domains: Vec<&XDomainInteger> = set
+                .construct_scope(&ids)?
+                .into_iter()
+                .map(|(_, d)| d)
+                .collect();
+            let tuples = symbolic_tuple_to_vector(tuple, &domains)?;
+            Ok(XExtension::new(scope_vec_str, set, tuples, is_support))
+        }
+
         pub fn new(
             scope: Vec<XVarVal>,
             set: &'a XVariableSet,
-            tuples: Vec<Vec<i32>>,
+            tuples: Vec<XTuple>,
             is_support: bool,
         ) -> Self {
             XExtension {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
@@ -143,8 +193,8 @@ pub mod xcsp3_core {
                 is_support,
             }
         }
-        ///return the iter of the supports tuples, if the value is i32::MAX, then it is the star
-        pub fn supports_iter(&self) -> Option<Iter<'_, Vec<i32>>> {
+        ///return the iter of the supports tuples, the star * is None
+        pub fn supports_iter(&self) -> Option<Iter<'_, XTuple>> {
             if self.is_support {
                 Some(self.tuples.iter())
             } else {
@@ -152,8 +202,8 @@ pub mod xcsp3_core {
             }
         }
 
-        ///return the iter of the conflict tuples, if the value is i32::MAX, then it is the star
-        pub fn conflicts_iter(&self) -> Option<Iter<'_, Vec<i32>>> {
+        ///return the iter of the conflict tuples, the star * is None
+        pub fn conflicts_iter(&self) -> Option<Iter<'_, XTuple>> {
             if !self.is_support {
                 Some(self.tuples.iter())
             } else {
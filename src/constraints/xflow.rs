@@ -0,0 +1,245 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 15:05
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::data_structs::xrelational_operand::xcsp3_core::Operand;
+    use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::{
+        list_size, list_to_values, list_to_vec_var_val, tuple_to_vector,
+    };
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    /// the weights and the condition are optional, they are given together.
+    pub struct XFlow<'a> {
+        scope: Vec<XVarVal>,
+        map: HashMap<String, &'a XDomainInteger>,
+        set: &'a XVariableSet,
+        balance: Vec<i64>,
+        arcs: Vec<(i64, i64)>,
+        weights: Option<Vec<i64>>,
+        operator: Option<Operator>,
+        operand: Option<Operand>,
+        reification: Option<XReification>,
+    }
+
+    impl Display for XFlow<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for e in self.scope.iter() {
+                ret.push('(');
+                ret.push_str(&e.to_string());
+                ret.push_str("), ")
+            }
+            ret.push_str(&format!(
+                " balance = {:?} arcs = {:?}",
+                self.balance, self.arcs
+            ));
+            if let Some(weights) = &self.weights {
+                ret.push_str(&format!(" weights = {:?}", weights));
+            }
+            if let (Some(operator), Some(operand)) = (&self.operator, &self.operand) {
+                ret.push_str(&format!(" condition = ({:?},{:?})", operator, operand));
+            }
+            write!(f, "XFlow: list =  {}", ret)
+        }
+    }
+
+    impl XConstraintTrait for XFlow<'_> {
+        fn get_scope_string(&self) -> &Vec<XVarVal> {
+            &self.scope
+        }
+
+        fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
+            for e in &self.scope {
+                if let XVarVal::IntVar(s) = e {
+                    if !self.map.contains_key(s) {
+                        if let Ok(vec) = self.set.construct_scope(&[s]) {
+                            for (vs, vv) in vec.into_iter() {
+                                self.map.insert(vs, vv);
+                            }
+                        }
+                    }
+                }
+            }
+            let mut scope_vec_var: Vec<(&String, &XDomainInteger)> = vec![];
+            for e in self.map.iter() {
+                scope_vec_var.push((e.0, e.1))
+            }
+            scope_vec_var
+        }
+    }
+
+    impl<'a> XFlow<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        pub fn from_str(
+            list: &str,
+            balance_str: &str,
+            arcs_str: &str,
+            weights_str: &str,
+            condition: &str,
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let scope = list_to_vec_var_val(list)?;
+            let balance = list_to_values(balance_str)?;
+            let mut arcs: Vec<(i64, i64)> = vec![];
+            match tuple_to_vector(arcs_str, false) {
+                Ok(tuples) => {
+                    for t in tuples.iter() {
+                        match t.as_slice() {
+                            [Some(from), Some(to)] => arcs.push((*from, *to)),
+                            _ => {
+                                return Err(Xcsp3Error::get_constraint_flow_error(
+                                    "parse flow constraint arcs error, ",
+                                ));
+                            }
+                        }
+                    }
+                }
+                Err(_) => {
+                    return Err(Xcsp3Error::get_constraint_flow_error(
+                        "parse flow constraint arcs error, ",
+                    ));
+                }
+            }
+            if list_size(&scope, set)? != arcs.len() {
+                return Err(Xcsp3Error::get_constraint_flow_error(
+                    "parse flow constraint error, the size of list is different from the number of arcs, ",
+                ));
+            }
+            if weights_str.trim().is_empty() != condition.trim().is_empty() {
+                return Err(Xcsp3Error::get_constraint_flow_error(
+                    "parse flow constraint error, weights and condition must be given together, ",
+                ));
+            }
+            if weights_str.trim().is_empty() {
+                return Ok(Self::new(scope, set, balance, arcs, None, None, None));
+            }
+            let weights = list_to_values(weights_str)?;
+            if weights.len() != arcs.len() {
+                return Err(Xcsp3Error::get_constraint_flow_error(
+                    "parse flow constraint error, the number of weights is different from the number of arcs, ",
+                ));
+            }
+            let condition = condition.replace(['(', ')', ','], " ");
+            let spilt: Vec<&str> = condition.split_whitespace().collect();
+            let ope: Operator = match spilt.first().and_then(|o| Operator::get_operator_by_str(o)) {
+                None => {
+                    return Err(Xcsp3Error::get_constraint_flow_error(
+                        "parse flow constraint Operator error, ",
+                    ));
+                }
+                Some(o) => o,
+            };
+            let rand: Operand = match Operand::get_operand_by_str(&spilt[1..], &ope) {
+                None => {
+                    return Err(Xcsp3Error::get_constraint_flow_error(
+                        "parse flow constraint Operand error, ",
+                    ));
+                }
+                Some(r) => r,
+            };
+            Ok(Self::new(
+                scope,
+                set,
+                balance,
+                arcs,
+                Some(weights),
+                Some(ope),
+                Some(rand),
+            ))
+        }
+
+        pub fn new(
+            scope: Vec<XVarVal>,
+            set: &'a XVariableSet,
+            balance: Vec<i64>,
+            arcs: Vec<(i64, i64)>,
+            weights: Option<Vec<i64>>,
+            operator: Option<Operator>,
+            operand: Option<Operand>,
+        ) -> Self {
+            Self {
+                reification: None,
+                scope,
+                map: Default::default(),
+                set,
+                balance,
+                arcs,
+                weights,
+                operator,
+                operand,
+            }
+        }
+
+        pub fn get_balance(&self) -> &Vec<i64> {
+            &self.balance
+        }
+
+        pub fn get_arcs(&self) -> &Vec<(i64, i64)> {
+            &self.arcs
+        }
+
+        pub fn get_weights(&self) -> &Option<Vec<i64>> {
+            &self.weights
+        }
+
+        pub fn get_operator(&self) -> &Option<Operator> {
+            &self.operator
+        }
+
+        pub fn get_operand(&self) -> &Option<Operand> {
+            &self.operand
+        }
+    }
+}
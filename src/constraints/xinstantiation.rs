@@ -41,11 +41,14 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use std::collections::HashMap;
     use std::fmt::{Display, Formatter};
 
-    use crate::utils::utils_functions::xcsp3_utils::{list_to_values, list_to_vec_var_val};
+    use crate::utils::utils_functions::xcsp3_utils::{
+        list_to_values, list_to_vec_var_val, symbolic_list_to_values,
+    };
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
     use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
 
@@ -54,7 +57,8 @@ pub mod xcsp3_core {
         scope: Vec<XVarVal>,
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
-        values: Vec<i32>,
+        values: Vec<i64>,
+        reification: Option<XReification>,
     }
 
     impl Display for XInstantiation<'_> {
@@ -96,11 +100,22 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XInstantiation<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             values_str: &str,
             set: &'a XVariableSet,
         ) -> Result<Self, Xcsp3Error> {
+            if values_str.contains(|c: char| c.is_ascii_alphabetic()) {
+                return Self::from_symbolic_str(list, values_str, set);
+            }
             match list_to_vec_var_val(list) {
                 Ok(scope_vec_str) => match list_to_values(values_str) {
                     Ok(values) => Ok(XInstantiation::new(scope_vec_str, set, values)),
@@ -110,15 +125,34 @@ pub mod xcsp3_core {
             }
         }
 
-        pub fn new(scope: Vec<XVarVal>, set: &'a XVariableSet, values: Vec<i32>) -> Self {
+        /// the symbols of values are mapped to integers by the domains of the list
+        fn from_symbolic_str(
+            list: &str,
+            values_str: &str,
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let scope_vec_str = list_to_vec_var_val(list)?;
+            let ids: Vec<String> = scope_vec_str.iter().map(|e| e.to_string()).collect();
+            let ids: Vec<&String> = ids.iter().collect();
+            let domains: Vec<&XDomainInteger> = set
+                .construct_scope(&ids)?
+                .into_iter()
+                .map(|(_, d)| d)
+                .collect();
+            let values = symbolic_list_to_values(values_str, &domains)?;
+            Ok(XInstantiation::new(scope_vec_str, set, values))
+        }
+
+        pub fn new(scope: Vec<XVarVal>, set: &'a XVariableSet, values: Vec<i64>) -> Self {
             XInstantiation {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
                 values,
             }
         }
-        pub fn get_values(&self) -> &Vec<i32> {
+        pub fn get_values(&self) -> &Vec<i64> {
             &self.values
         }
     }
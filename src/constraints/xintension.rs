@@ -40,12 +40,17 @@
 
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
-    use crate::data_structs::expression_tree::xcsp3_utils::ExpressionTree;
+    use crate::constraints::xprimitive::xcsp3_core::{relational, XPrimitive};
+    use crate::constraints::xsum::xcsp3_core::XSum;
+    use crate::data_structs::expression_tree::xcsp3_utils::{ExpressionTree, Operator, TreeNode};
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::data_structs::xrelational_operand::xcsp3_core::Operand;
+    use crate::data_structs::xrelational_operator::xcsp3_core::Operator as RelationalOperator;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
     use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::fmt::{Display, Formatter};
 
     // #[derive(Clone)]
@@ -54,6 +59,7 @@ pub mod xcsp3_core {
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
         tree: ExpressionTree,
+        reification: Option<XReification>,
     }
 
     impl Display for XIntention<'_> {
@@ -100,16 +106,99 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XIntention<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn get_expression(&self) -> &ExpressionTree {
             &self.tree
         }
 
+        /// return the primitive form of the constraint, eg. x op k, x op y + k, x = y op z,
+        /// x in set or a clause, None if the expression is not primitive
+        pub fn get_primitive(&self) -> Option<XPrimitive> {
+            XPrimitive::from_expression(&self.tree, self.set)
+        }
+
+        /// transform the linear (in)equality into a sum, eg. le(add(mul(3,x),mul(2,y),z),10) ->
+        /// sum of (x,y,z) with the coeffs (3,2,1) and the condition (le,10), return an error if it is not linear
+        pub fn to_sum(&self) -> Result<XSum<'a>, Xcsp3Error> {
+            let not_linear = || {
+                Xcsp3Error::get_constraint_linear_error(&format!(
+                    "the expression {} is not linear, ",
+                    self.tree.get_root().to_expression()
+                ))
+            };
+            let (operator, (terms, constant), values) = match self.tree.get_root() {
+                TreeNode::Operator(Operator::In, children) => match children.as_slice() {
+                    [a, TreeNode::Operator(Operator::Set, values)] => (
+                        RelationalOperator::In,
+                        a.to_linear().ok_or_else(not_linear)?,
+                        Some(values),
+                    ),
+                    _ => return Err(not_linear()),
+                },
+                TreeNode::Operator(ope, children) => match (relational(ope), children.as_slice()) {
+                    (Some(operator), [a, b]) => (
+                        operator,
+                        TreeNode::Operator(Operator::Sub, vec![a.clone(), b.clone()])
+                            .to_linear()
+                            .ok_or_else(not_linear)?,
+                        None,
+                    ),
+                    _ => return Err(not_linear()),
+                },
+                _ => return Err(not_linear()),
+            };
+            if terms.is_empty() {
+                return Err(Xcsp3Error::get_constraint_linear_error(&format!(
+                    "the linear expression {} has no variable, ",
+                    self.tree.get_root().to_expression()
+                )));
+            }
+            // sum + constant op k -> sum op k - constant
+            let operand = match values {
+                None => Operand::Integer(constant.checked_neg().ok_or_else(not_linear)?),
+                Some(values) => {
+                    let mut set: HashSet<i64> = HashSet::new();
+                    for v in values.iter() {
+                        match v {
+                            TreeNode::Constant(k) => {
+                                set.insert(k.checked_sub(constant).ok_or_else(not_linear)?);
+                            }
+                            _ => return Err(not_linear()),
+                        }
+                    }
+                    Operand::SetInteger(set)
+                }
+            };
+            let coeffs = if terms.iter().all(|(_, c)| *c == 1) {
+                None
+            } else {
+                Some(terms.iter().map(|(_, c)| XVarVal::IntVal(*c)).collect())
+            };
+            let scope = terms.into_iter().map(|(x, _)| XVarVal::IntVar(x)).collect();
+            Ok(XSum::new(scope, self.set, operator, operand, coeffs))
+        }
+
+        /// replace the expression by its simplified one, the variables which disappear are removed from the scope
+        pub fn simplify(&mut self) {
+            self.tree = self.tree.simplify();
+            self.scope = self.tree.get(self.set);
+            self.map.clear();
+        }
+
         pub fn from_str_without_scope(
             expression: &str,
             set: &'a XVariableSet,
         ) -> Result<Self, Xcsp3Error> {
             match ExpressionTree::from_string(expression) {
-                Ok(tree) => {
+                Ok(mut tree) => {
+                    tree.replace_symbolic(set);
                     let scope: Vec<XVarVal> = tree.get(set);
                     Ok(Self::new(scope, set, tree))
                 }
@@ -119,6 +208,7 @@ pub mod xcsp3_core {
 
         pub fn new(scope: Vec<XVarVal>, set: &'a XVariableSet, tree: ExpressionTree) -> Self {
             Self {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
@@ -0,0 +1,231 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 14:40
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::data_structs::xrelational_operand::xcsp3_core::Operand;
+    use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::{
+        list_size, list_to_values, list_to_vec_var_val,
+    };
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    pub struct XKnapsack<'a> {
+        scope: Vec<XVarVal>,
+        map: HashMap<String, &'a XDomainInteger>,
+        set: &'a XVariableSet,
+        weights: Vec<i64>,
+        profits: Vec<i64>,
+        weight_operator: Operator,
+        weight_operand: Operand,
+        profit_operator: Operator,
+        profit_operand: Operand,
+        reification: Option<XReification>,
+    }
+
+    impl Display for XKnapsack<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for e in self.scope.iter() {
+                ret.push('(');
+                ret.push_str(&e.to_string());
+                ret.push_str("), ")
+            }
+            write!(
+                f,
+                "XKnapsack: list =  {} weights = {:?} profits = {:?} weight condition = ({:?},{:?}) profit condition = ({:?},{:?})",
+                ret,
+                self.weights,
+                self.profits,
+                self.weight_operator,
+                self.weight_operand,
+                self.profit_operator,
+                self.profit_operand
+            )
+        }
+    }
+
+    impl XConstraintTrait for XKnapsack<'_> {
+        fn get_scope_string(&self) -> &Vec<XVarVal> {
+            &self.scope
+        }
+
+        fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
+            for e in &self.scope {
+                if let XVarVal::IntVar(s) = e {
+                    if !self.map.contains_key(s) {
+                        if let Ok(vec) = self.set.construct_scope(&[s]) {
+                            for (vs, vv) in vec.into_iter() {
+                                self.map.insert(vs, vv);
+                            }
+                        }
+                    }
+                }
+            }
+            let mut scope_vec_var: Vec<(&String, &XDomainInteger)> = vec![];
+            for e in self.map.iter() {
+                scope_vec_var.push((e.0, e.1))
+            }
+            scope_vec_var
+        }
+    }
+
+    impl<'a> XKnapsack<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        /// the first condition is on the total weight, the second one is on the total profit
+        pub fn from_str(
+            list: &str,
+            weights_str: &str,
+            profits_str: &str,
+            conditions: &[String],
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let scope = list_to_vec_var_val(list)?;
+            let weights = list_to_values(weights_str)?;
+            let profits = list_to_values(profits_str)?;
+            if weights.len() != profits.len() {
+                return Err(Xcsp3Error::get_constraint_knapsack_error(
+                    "parse knapsack constraint error, the number of weights is different from the number of profits, ",
+                ));
+            }
+            if weights.len() != list_size(&scope, set)? {
+                return Err(Xcsp3Error::get_constraint_knapsack_error(
+                    "parse knapsack constraint error, the number of weights is different from the size of list, ",
+                ));
+            }
+            if conditions.len() != 2 {
+                return Err(Xcsp3Error::get_constraint_knapsack_error(
+                    "parse knapsack constraint error, two conditions are required, ",
+                ));
+            }
+            let weight_condition = Self::parse_condition(&conditions[0])?;
+            let profit_condition = Self::parse_condition(&conditions[1])?;
+            Ok(Self::new(
+                scope,
+                set,
+                weights,
+                profits,
+                weight_condition,
+                profit_condition,
+            ))
+        }
+
+        fn parse_condition(condition: &str) -> Result<(Operator, Operand), Xcsp3Error> {
+            let condition = condition.replace(['(', ')', ','], " ");
+            let spilt: Vec<&str> = condition.split_whitespace().collect();
+            let ope: Operator = match spilt.first().and_then(|o| Operator::get_operator_by_str(o)) {
+                None => {
+                    return Err(Xcsp3Error::get_constraint_knapsack_error(
+                        "parse knapsack constraint Operator error, ",
+                    ));
+                }
+                Some(o) => o,
+            };
+            let rand: Operand = match Operand::get_operand_by_str(&spilt[1..], &ope) {
+                None => {
+                    return Err(Xcsp3Error::get_constraint_knapsack_error(
+                        "parse knapsack constraint Operand error, ",
+                    ));
+                }
+                Some(r) => r,
+            };
+            Ok((ope, rand))
+        }
+
+        pub fn new(
+            scope: Vec<XVarVal>,
+            set: &'a XVariableSet,
+            weights: Vec<i64>,
+            profits: Vec<i64>,
+            weight_condition: (Operator, Operand),
+            profit_condition: (Operator, Operand),
+        ) -> Self {
+            Self {
+                reification: None,
+                scope,
+                map: Default::default(),
+                set,
+                weights,
+                profits,
+                weight_operator: weight_condition.0,
+                weight_operand: weight_condition.1,
+                profit_operator: profit_condition.0,
+                profit_operand: profit_condition.1,
+            }
+        }
+
+        pub fn get_weights(&self) -> &Vec<i64> {
+            &self.weights
+        }
+
+        pub fn get_profits(&self) -> &Vec<i64> {
+            &self.profits
+        }
+
+        pub fn get_weight_operator(&self) -> &Operator {
+            &self.weight_operator
+        }
+
+        pub fn get_weight_operand(&self) -> &Operand {
+            &self.weight_operand
+        }
+
+        pub fn get_profit_operator(&self) -> &Operator {
+            &self.profit_operator
+        }
+
+        pub fn get_profit_operand(&self) -> &Operand {
+            &self.profit_operand
+        }
+    }
+}
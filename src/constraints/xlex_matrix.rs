@@ -0,0 +1,181 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 11:20
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::{list_size, list_to_matrix_ids};
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    /// both the rows and the columns of the matrix are lexicographically ordered
+    pub struct XLexMatrix<'a> {
+        scope: Vec<XVarVal>,
+        matrix: Vec<Vec<XVarVal>>,
+        map: HashMap<String, &'a XDomainInteger>,
+        set: &'a XVariableSet,
+        operator: Operator,
+        reification: Option<XReification>,
+    }
+
+    impl<'a> XLexMatrix<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        pub fn from_str(
+            matrix_str: &str,
+            operator_str: &str,
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let mut matrix: Vec<Vec<XVarVal>> = vec![];
+            for line in list_to_matrix_ids(matrix_str).iter() {
+                let mut row: Vec<XVarVal> = vec![];
+                for e in line.iter() {
+                    match XVarVal::from_string(e) {
+                        None => {
+                            return Err(Xcsp3Error::get_constraint_lex_error(
+                                "parse lex constraint matrix error, ",
+                            ));
+                        }
+                        Some(v) => row.push(v),
+                    }
+                }
+                matrix.push(row);
+            }
+            if matrix.is_empty() {
+                return Err(Xcsp3Error::get_constraint_lex_error(
+                    "parse lex constraint matrix error, ",
+                ));
+            }
+            let size = list_size(&matrix[0], set)?;
+            for row in matrix.iter().skip(1) {
+                if list_size(row, set)? != size {
+                    return Err(Xcsp3Error::get_constraint_lex_error(
+                        "parse lex constraint matrix error, the rows have different lengths, ",
+                    ));
+                }
+            }
+            let operator = match Operator::get_operator_by_str(operator_str.trim()) {
+                Some(o @ (Operator::Lt | Operator::Le | Operator::Gt | Operator::Ge)) => o,
+                _ => {
+                    return Err(Xcsp3Error::get_constraint_lex_error(
+                        "parse lex constraint operator error, ",
+                    ));
+                }
+            };
+            Ok(Self::new(matrix, set, operator))
+        }
+
+        pub fn new(matrix: Vec<Vec<XVarVal>>, set: &'a XVariableSet, operator: Operator) -> Self {
+            Self {
+                reification: None,
+                scope: matrix.concat(),
+                matrix,
+                map: Default::default(),
+                set,
+                operator,
+            }
+        }
+
+        pub fn get_operator(&self) -> &Operator {
+            &self.operator
+        }
+
+        pub fn get_matrix(&self) -> &Vec<Vec<XVarVal>> {
+            &self.matrix
+        }
+    }
+
+    impl XConstraintTrait for XLexMatrix<'_> {
+        fn get_scope_string(&self) -> &Vec<XVarVal> {
+            &self.scope
+        }
+
+        fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
+            for e in &self.scope {
+                if let XVarVal::IntVar(s) = e {
+                    if !self.map.contains_key(s) {
+                        if let Ok(vec) = self.set.construct_scope(&[s]) {
+                            for (vs, vv) in vec.into_iter() {
+                                self.map.insert(vs, vv);
+                            }
+                        }
+                    }
+                }
+            }
+            let mut scope_vec_var: Vec<(&String, &XDomainInteger)> = vec![];
+            for e in self.map.iter() {
+                scope_vec_var.push((e.0, e.1))
+            }
+            scope_vec_var
+        }
+    }
+
+    impl Display for XLexMatrix<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for vc in self.matrix.iter() {
+                ret.push('(');
+                for (j, e) in vc.iter().enumerate() {
+                    ret.push_str(&e.to_string());
+                    if j != vc.len() - 1 {
+                        ret.push_str(", ");
+                    }
+                }
+                ret.push(')');
+            }
+            write!(
+                f,
+                "XLexMatrix: matrix =  {}, operator = {:?}",
+                ret, self.operator
+            )
+        }
+    }
+}
@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::utils::utils_functions::xcsp3_utils::{list_to_transitions, list_to_vec_var_val};
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
@@ -53,7 +54,8 @@ pub mod xcsp3_core {
         scope: Vec<XVarVal>,
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
-        transitions: Vec<(String, i32, String)>,
+        transitions: Vec<(String, i64, String)>,
+        reification: Option<XReification>,
     }
 
     impl Display for XMdd<'_> {
@@ -95,6 +97,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XMdd<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             transitions_str: &str,
@@ -109,15 +119,16 @@ pub mod xcsp3_core {
             }
         }
 
-        pub fn get_transitions(&self) -> &Vec<(String, i32, String)> {
+        pub fn get_transitions(&self) -> &Vec<(String, i64, String)> {
             &self.transitions
         }
         pub fn new(
             scope: Vec<XVarVal>,
             set: &'a XVariableSet,
-            transitions: Vec<(String, i32, String)>,
+            transitions: Vec<(String, i64, String)>,
         ) -> Self {
             XMdd {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
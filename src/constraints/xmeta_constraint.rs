@@ -0,0 +1,131 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 15:40
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_type::xcsp3_core::XConstraintType;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum XMetaType {
+        And,
+        Or,
+        Not,
+        IfThen,
+        IfThenElse,
+    }
+
+    /// a meta-constraint combines its children, which can be meta-constraints too.
+    /// for ifThen and ifThenElse, the first child is the condition.
+    pub struct XMetaConstraint<'a> {
+        meta_type: XMetaType,
+        children: Vec<XConstraintType<'a>>,
+        reification: Option<XReification>,
+    }
+
+    impl Display for XMetaConstraint<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for e in self.children.iter() {
+                ret.push('(');
+                ret.push_str(&e.to_string());
+                ret.push_str("), ")
+            }
+            write!(f, "XMetaConstraint: {:?} [ {}]", self.meta_type, ret)
+        }
+    }
+
+    impl<'a> XMetaConstraint<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        pub fn from_children(
+            meta_type: XMetaType,
+            children: Vec<XConstraintType<'a>>,
+        ) -> Result<Self, Xcsp3Error> {
+            let arity_ok = match meta_type {
+                XMetaType::And | XMetaType::Or => children.len() >= 2,
+                XMetaType::Not => children.len() == 1,
+                XMetaType::IfThen => children.len() == 2,
+                XMetaType::IfThenElse => children.len() == 3,
+            };
+            if !arity_ok {
+                return Err(Xcsp3Error::get_constraint_meta_error(&format!(
+                    "parse {:?} meta-constraint error, wrong number of constraints, ",
+                    meta_type
+                )));
+            }
+            let mut ret: Vec<XConstraintType<'a>> = Vec::with_capacity(children.len());
+            for c in children.into_iter() {
+                match c {
+                    XConstraintType::XConstraintNone(e) => return Err(e),
+                    _ => ret.push(c),
+                }
+            }
+            Ok(Self::new(meta_type, ret))
+        }
+
+        pub fn new(meta_type: XMetaType, children: Vec<XConstraintType<'a>>) -> Self {
+            Self {
+                reification: None,
+                meta_type,
+                children,
+            }
+        }
+
+        pub fn get_meta_type(&self) -> &XMetaType {
+            &self.meta_type
+        }
+
+        pub fn get_children(&self) -> &Vec<XConstraintType<'a>> {
+            &self.children
+        }
+
+        pub fn get_children_mut(&mut self) -> &mut Vec<XConstraintType<'a>> {
+            &mut self.children
+        }
+    }
+}
@@ -39,6 +39,7 @@
  */
 pub mod xcsp3_core {
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::utils::utils_functions::xcsp3_utils::list_to_vec_var_val;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
@@ -52,9 +53,18 @@ pub mod xcsp3_core {
         map: HashMap<String, &'a XDomainInteger>,
         set: &'a XVariableSet,
         zero_ignored: Option<bool>,
+        reification: Option<XReification>,
     }
 
     impl<'a> XNoOverlapKDim<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             lengths_str: &str,
@@ -110,6 +120,7 @@ pub mod xcsp3_core {
             zero_ignored: Option<bool>,
         ) -> Self {
             Self {
+                reification: None,
                 scope,
                 lengths,
                 map: Default::default(),
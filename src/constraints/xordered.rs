@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use std::collections::HashMap;
@@ -57,6 +58,7 @@ pub mod xcsp3_core {
         set: &'a XVariableSet,
         lengths: Option<Vec<XVarVal>>,
         operator: Operator,
+        reification: Option<XReification>,
     }
 
     impl Display for XOrdered<'_> {
@@ -111,6 +113,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XOrdered<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             lengths_str: &str,
@@ -156,6 +166,7 @@ pub mod xcsp3_core {
             operator: Operator,
         ) -> Self {
             XOrdered {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
@@ -0,0 +1,183 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 11:45
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::{list_size, list_to_vec_var_val};
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    pub struct XPrecedence<'a> {
+        scope: Vec<XVarVal>,
+        map: HashMap<String, &'a XDomainInteger>,
+        set: &'a XVariableSet,
+        /// when values are absent, all values of the union of domains are considered
+        values: Option<Vec<XVarVal>>,
+        covered: Option<bool>,
+        reification: Option<XReification>,
+    }
+
+    impl<'a> XPrecedence<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        pub fn from_str(
+            list: &str,
+            values_str: &str,
+            covered_str: &str,
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let scope = list_to_vec_var_val(list)?;
+            if list_size(&scope, set)? < 2 {
+                return Err(Xcsp3Error::get_constraint_precedence_error(
+                    "parse precedence constraint error, the list needs at least two variables, ",
+                ));
+            }
+            let values = if values_str.trim().is_empty() {
+                None
+            } else {
+                match list_to_vec_var_val(values_str) {
+                    Ok(s) => Some(s),
+                    Err(e) => return Err(e),
+                }
+            };
+            let covered = if !covered_str.is_empty() {
+                match covered_str.parse::<bool>() {
+                    Ok(n) => Some(n),
+                    Err(_) => {
+                        return Err(Xcsp3Error::get_constraint_precedence_error(
+                            "parse precedence constraint covered error, ",
+                        ));
+                    }
+                }
+            } else {
+                None
+            };
+            if covered == Some(true) && values.is_none() {
+                return Err(Xcsp3Error::get_constraint_precedence_error(
+                    "parse precedence constraint error, covered requires values, ",
+                ));
+            }
+            Ok(Self::new(scope, set, values, covered))
+        }
+
+        pub fn new(
+            scope: Vec<XVarVal>,
+            set: &'a XVariableSet,
+            values: Option<Vec<XVarVal>>,
+            covered: Option<bool>,
+        ) -> Self {
+            Self {
+                reification: None,
+                scope,
+                map: Default::default(),
+                set,
+                values,
+                covered,
+            }
+        }
+
+        pub fn get_values(&self) -> &Option<Vec<XVarVal>> {
+            &self.values
+        }
+
+        pub fn get_covered(&self) -> &Option<bool> {
+            &self.covered
+        }
+    }
+
+    impl Display for XPrecedence<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for e in self.scope.iter() {
+                ret.push('(');
+                ret.push_str(&e.to_string());
+                ret.push_str("), ")
+            }
+            if let Some(values) = &self.values {
+                ret.push_str("  values = ");
+                for e in values.iter() {
+                    ret.push('(');
+                    ret.push_str(&e.to_string());
+                    ret.push_str("), ")
+                }
+            }
+            if let Some(c) = self.covered {
+                ret.push_str(&format!("  covered = {}", c));
+            }
+            write!(f, "XPrecedence: list =  {}", ret)
+        }
+    }
+
+    impl XConstraintTrait for XPrecedence<'_> {
+        fn get_scope_string(&self) -> &Vec<XVarVal> {
+            &self.scope
+        }
+
+        fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
+            for e in &self.scope {
+                if let XVarVal::IntVar(s) = e {
+                    if !self.map.contains_key(s) {
+                        if let Ok(vec) = self.set.construct_scope(&[s]) {
+                            for (vs, vv) in vec.into_iter() {
+                                self.map.insert(vs, vv);
+                            }
+                        }
+                    }
+                }
+            }
+            let mut scope_vec_var: Vec<(&String, &XDomainInteger)> = vec![];
+            for e in self.map.iter() {
+                scope_vec_var.push((e.0, e.1))
+            }
+            scope_vec_var
+        }
+    }
+}
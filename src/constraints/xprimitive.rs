@@ -0,0 +1,337 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 14:05
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::data_structs::expression_tree::xcsp3_utils::{
+        ExpressionTree, Operator as ExpressionOperator, TreeNode,
+    };
+    use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::fmt::{Display, Formatter};
+
+    /// the primitive forms of intension constraints, which can be posted by specialized propagators
+    #[derive(Clone, Debug)]
+    pub enum XPrimitive {
+        /// x op k
+        Unary {
+            x: String,
+            operator: Operator,
+            k: i64,
+        },
+        /// x op y + k
+        Binary {
+            x: String,
+            operator: Operator,
+            y: String,
+            k: i64,
+        },
+        /// x = y op z, where op is an arithmetic operator, eg. add, mul, dist
+        Ternary {
+            x: String,
+            y: String,
+            operator: ExpressionOperator,
+            z: String,
+        },
+        /// x in {values} or x notin {values}
+        Set {
+            x: String,
+            operator: Operator,
+            values: Vec<i64>,
+        },
+        /// the disjunction of the positive literals and the negation of the negative ones
+        Clause {
+            positive: Vec<String>,
+            negative: Vec<String>,
+        },
+    }
+
+    impl Display for XPrimitive {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                XPrimitive::Unary { x, operator, k } => {
+                    write!(f, "XPrimitive: {} {:?} {}", x, operator, k)
+                }
+                XPrimitive::Binary { x, operator, y, k } => {
+                    write!(f, "XPrimitive: {} {:?} {} + {}", x, operator, y, k)
+                }
+                XPrimitive::Ternary { x, y, operator, z } => {
+                    write!(f, "XPrimitive: {} = {} {:?} {}", x, y, operator, z)
+                }
+                XPrimitive::Set {
+                    x,
+                    operator,
+                    values,
+                } => write!(f, "XPrimitive: {} {:?} {:?}", x, operator, values),
+                XPrimitive::Clause { positive, negative } => write!(
+                    f,
+                    "XPrimitive: clause positive = {:?} negative = {:?}",
+                    positive, negative
+                ),
+            }
+        }
+    }
+
+    impl XPrimitive {
+        /// recognize the primitive form of the simplified expression, None if it is not primitive,
+        /// the set gives the domains of the variables, a clause is only made of 0/1 variables
+        pub fn from_expression(tree: &ExpressionTree, set: &XVariableSet) -> Option<XPrimitive> {
+            let tree = tree.simplify();
+            match tree.get_root() {
+                TreeNode::Operator(ExpressionOperator::In, children) => {
+                    XPrimitive::from_in(children, Operator::In)
+                }
+                TreeNode::Operator(ExpressionOperator::Not, children) => {
+                    match children.as_slice() {
+                        [TreeNode::Operator(ExpressionOperator::In, children)] => {
+                            XPrimitive::from_in(children, Operator::Notin)
+                        }
+                        _ => None,
+                    }
+                }
+                TreeNode::Operator(ExpressionOperator::Or, children) => {
+                    XPrimitive::from_literals(children, &[], set)
+                }
+                TreeNode::Operator(ExpressionOperator::Imp, children) => {
+                    match children.as_slice() {
+                        [a, b] => XPrimitive::from_literals(
+                            std::slice::from_ref(b),
+                            std::slice::from_ref(a),
+                            set,
+                        ),
+                        _ => None,
+                    }
+                }
+                TreeNode::Operator(ope, children) => match (relational(ope), children.as_slice()) {
+                    (Some(operator), [a, b]) => XPrimitive::from_relation(operator, a, b),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+
+        fn from_in(children: &[TreeNode], operator: Operator) -> Option<XPrimitive> {
+            match children {
+                [TreeNode::Variable(x), TreeNode::Operator(ExpressionOperator::Set, set)] => {
+                    let mut values: Vec<i64> = Vec::with_capacity(set.len());
+                    for v in set.iter() {
+                        match v {
+                            TreeNode::Constant(c) => values.push(*c),
+                            _ => return None,
+                        }
+                    }
+                    Some(XPrimitive::Set {
+                        x: x.clone(),
+                        operator,
+                        values,
+                    })
+                }
+                _ => None,
+            }
+        }
+
+        /// the clause of the positive literals and the negation of the negative ones,
+        /// a literal is x, not(x), eq(x,0), eq(x,1) or ne(x,0) where x is a 0/1 variable
+        fn from_literals(
+            positive: &[TreeNode],
+            negative: &[TreeNode],
+            set: &XVariableSet,
+        ) -> Option<XPrimitive> {
+            let mut clause = (vec![], vec![]);
+            for (literals, is_positive) in [(positive, true), (negative, false)] {
+                for l in literals.iter() {
+                    let (x, sign) = match l {
+                        TreeNode::Variable(x) => (x, true),
+                        TreeNode::Operator(ExpressionOperator::Not, c) => match c.as_slice() {
+                            [TreeNode::Variable(x)] => (x, false),
+                            _ => return None,
+                        },
+                        TreeNode::Operator(ope, c) => match (ope, c.as_slice()) {
+                            (
+                                ExpressionOperator::Eq,
+                                [TreeNode::Variable(x), TreeNode::Constant(0)],
+                            ) => (x, false),
+                            (
+                                ExpressionOperator::Eq,
+                                [TreeNode::Variable(x), TreeNode::Constant(1)],
+                            )
+                            | (
+                                ExpressionOperator::Ne,
+                                [TreeNode::Variable(x), TreeNode::Constant(0)],
+                            ) => (x, true),
+                            _ => return None,
+                        },
+                        _ => return None,
+                    };
+                    if !is_boolean_variable(x, set) {
+                        return None;
+                    }
+                    if sign == is_positive {
+                        clause.0.push(x.clone());
+                    } else {
+                        clause.1.push(x.clone());
+                    }
+                }
+            }
+            Some(XPrimitive::Clause {
+                positive: clause.0,
+                negative: clause.1,
+            })
+        }
+
+        /// a op b, where a and b are x, k, add(x,k) or sub(x,k), or x = y op z
+        fn from_relation(operator: Operator, a: &TreeNode, b: &TreeNode) -> Option<XPrimitive> {
+            if let Operator::Eq = operator {
+                for (x, e) in [(a, b), (b, a)] {
+                    if let (TreeNode::Variable(x), TreeNode::Operator(ope, c)) = (x, e) {
+                        if let [TreeNode::Variable(y), TreeNode::Variable(z)] = c.as_slice() {
+                            if arithmetic(ope) {
+                                return Some(XPrimitive::Ternary {
+                                    x: x.clone(),
+                                    y: y.clone(),
+                                    operator: ope.clone(),
+                                    z: z.clone(),
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            // sub(x,y) op k -> x op y + k
+            if let (TreeNode::Operator(ExpressionOperator::Sub, c), TreeNode::Constant(k)) = (a, b)
+            {
+                if let [TreeNode::Variable(x), TreeNode::Variable(y)] = c.as_slice() {
+                    return Some(XPrimitive::Binary {
+                        x: x.clone(),
+                        operator,
+                        y: y.clone(),
+                        k: *k,
+                    });
+                }
+            }
+            match (variable_plus_constant(a)?, variable_plus_constant(b)?) {
+                ((Some(x), ka), (Some(y), kb)) => Some(XPrimitive::Binary {
+                    x,
+                    operator,
+                    y,
+                    k: kb.checked_sub(ka)?,
+                }),
+                ((Some(x), ka), (None, kb)) => Some(XPrimitive::Unary {
+                    x,
+                    operator,
+                    k: kb.checked_sub(ka)?,
+                }),
+                ((None, ka), (Some(y), kb)) => Some(XPrimitive::Unary {
+                    x: y,
+                    operator: mirror(operator),
+                    k: ka.checked_sub(kb)?,
+                }),
+                _ => None,
+            }
+        }
+    }
+
+    pub(crate) fn relational(ope: &ExpressionOperator) -> Option<Operator> {
+        match ope {
+            ExpressionOperator::Lt => Some(Operator::Lt),
+            ExpressionOperator::Le => Some(Operator::Le),
+            ExpressionOperator::Ge => Some(Operator::Ge),
+            ExpressionOperator::Gt => Some(Operator::Gt),
+            ExpressionOperator::Eq => Some(Operator::Eq),
+            ExpressionOperator::Ne => Some(Operator::Ne),
+            _ => None,
+        }
+    }
+
+    fn is_boolean_variable(x: &String, set: &XVariableSet) -> bool {
+        match set.construct_scope(&[x]) {
+            Ok(scope) => {
+                !scope.is_empty()
+                    && scope.iter().all(|(_, d)| {
+                        matches!((d.minimum(), d.maximum()), (Some(min), Some(max)) if min >= 0 && max <= 1)
+                    })
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// the operator of y op x when the relation is x op y
+    fn mirror(operator: Operator) -> Operator {
+        match operator {
+            Operator::Lt => Operator::Gt,
+            Operator::Le => Operator::Ge,
+            Operator::Ge => Operator::Le,
+            Operator::Gt => Operator::Lt,
+            _ => operator,
+        }
+    }
+
+    fn arithmetic(ope: &ExpressionOperator) -> bool {
+        matches!(
+            ope,
+            ExpressionOperator::Add
+                | ExpressionOperator::Sub
+                | ExpressionOperator::Mul
+                | ExpressionOperator::Div
+                | ExpressionOperator::Mod
+                | ExpressionOperator::Pow
+                | ExpressionOperator::Min
+                | ExpressionOperator::Max
+                | ExpressionOperator::Dist
+        )
+    }
+
+    /// x -> (x, 0), k -> (None, k), add(x,k) -> (x, k), sub(x,k) -> (x, -k)
+    fn variable_plus_constant(node: &TreeNode) -> Option<(Option<String>, i64)> {
+        match node {
+            TreeNode::Variable(x) => Some((Some(x.clone()), 0)),
+            TreeNode::Constant(k) => Some((None, *k)),
+            TreeNode::Operator(ope, c) => match (ope, c.as_slice()) {
+                (ExpressionOperator::Add, [TreeNode::Variable(x), TreeNode::Constant(k)]) => {
+                    Some((Some(x.clone()), *k))
+                }
+                (ExpressionOperator::Sub, [TreeNode::Variable(x), TreeNode::Constant(k)]) => {
+                    Some((Some(x.clone()), k.checked_neg()?))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use std::collections::HashMap;
     use std::fmt::{Display, Formatter};
@@ -56,7 +57,8 @@ pub mod xcsp3_core {
         set: &'a XVariableSet,
         start: String,
         r#final: Vec<String>,
-        transitions: Vec<(String, i32, String)>,
+        transitions: Vec<(String, i64, String)>,
+        reification: Option<XReification>,
     }
 
     impl Display for XRegular<'_> {
@@ -101,6 +103,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XRegular<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             transitions_str: &str,
@@ -135,9 +145,10 @@ pub mod xcsp3_core {
             set: &'a XVariableSet,
             start: String,
             r#final: Vec<String>,
-            transitions: Vec<(String, i32, String)>,
+            transitions: Vec<(String, i64, String)>,
         ) -> Self {
             XRegular {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
@@ -155,7 +166,7 @@ pub mod xcsp3_core {
             &self.r#final
         }
 
-        pub fn get_transitions(&self) -> &Vec<(String, i32, String)> {
+        pub fn get_transitions(&self) -> &Vec<(String, i64, String)> {
             &self.transitions
         }
     }
@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_type::xcsp3_core::XConstraintType;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::utils::utils_functions::xcsp3_utils::list_to_vec_var_val;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
@@ -55,9 +56,18 @@ pub mod xcsp3_core {
         template: Box<XConstraintType<'a>>,
         circular: bool,
         offset: i32,
+        reification: Option<XReification>,
     }
 
     impl<'a> XSlide<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn get_offset(&self) -> i32 {
             self.offset
         }
@@ -165,6 +175,7 @@ pub mod xcsp3_core {
             template: Box<XConstraintType<'a>>,
         ) -> Self {
             Self {
+                reification: None,
                 args,
                 map: Default::default(),
                 set,
@@ -0,0 +1,183 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 11:02
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_type::xcsp3_core::XConstraintType;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::XTuple;
+    use std::fmt::{Display, Formatter};
+    use std::str::FromStr;
+
+    /// the cost semantics of a soft constraint
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum XSoftCost {
+        /// a soft extension, the i-th cost is the cost of the i-th tuple, the star * is None,
+        /// and the other tuples cost default_cost
+        Tuples {
+            default_cost: i64,
+            tuples: Vec<XTuple>,
+            costs: Vec<i64>,
+        },
+        /// a relaxed constraint, a violation costs violation_cost, eg. the measure can be var or dec
+        Violation {
+            measure: Option<String>,
+            violation_cost: Option<i64>,
+        },
+    }
+
+    /// a soft constraint wraps the constraint it relaxes, eg. a soft extension wraps an extension
+    /// on its list, which forbids no tuple since the tuples and their costs are in XSoftCost::Tuples
+    pub struct XSoftConstraint<'a> {
+        constraint: Box<XConstraintType<'a>>,
+        cost: XSoftCost,
+        /// the forbidden cost, a tuple whose cost reaches it is not allowed
+        ub: Option<i64>,
+        reification: Option<XReification>,
+    }
+
+    impl Display for XSoftConstraint<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "XSoftConstraint: cost = {:?} ub = {:?} constraint = ({})",
+                self.cost, self.ub, self.constraint
+            )
+        }
+    }
+
+    impl<'a> XSoftConstraint<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        /// construct a relaxed constraint from the attributes violationMeasure, violationCost and ub
+        pub fn from_violation(
+            constraint: XConstraintType<'a>,
+            measure: &str,
+            violation_cost: &str,
+            ub: &str,
+        ) -> Result<Self, Xcsp3Error> {
+            if let XConstraintType::XConstraintNone(e) = constraint {
+                return Err(e);
+            }
+            let measure = if measure.trim().is_empty() {
+                None
+            } else {
+                Some(measure.trim().to_string())
+            };
+            let cost = XSoftCost::Violation {
+                measure,
+                violation_cost: XSoftConstraint::parse_cost(violation_cost)?,
+            };
+            Ok(Self::new(
+                constraint,
+                cost,
+                XSoftConstraint::parse_cost(ub)?,
+            ))
+        }
+
+        /// construct a soft extension, the costs are given in the order of the tuples
+        pub fn from_tuples(
+            constraint: XConstraintType<'a>,
+            default_cost: &str,
+            tuples: Vec<XTuple>,
+            costs: Vec<i64>,
+            ub: &str,
+        ) -> Result<Self, Xcsp3Error> {
+            if let XConstraintType::XConstraintNone(e) = constraint {
+                return Err(e);
+            }
+            let cost = XSoftCost::Tuples {
+                default_cost: XSoftConstraint::parse_cost(default_cost)?.unwrap_or(0),
+                tuples,
+                costs,
+            };
+            Ok(Self::new(
+                constraint,
+                cost,
+                XSoftConstraint::parse_cost(ub)?,
+            ))
+        }
+
+        fn parse_cost(cost: &str) -> Result<Option<i64>, Xcsp3Error> {
+            let cost = cost.trim();
+            if cost.is_empty() {
+                return Ok(None);
+            }
+            match i64::from_str(cost) {
+                Ok(n) => Ok(Some(n)),
+                Err(_) => Err(Xcsp3Error::get_constraint_soft_error(&format!(
+                    "parse the cost {} of soft constraint error, ",
+                    cost
+                ))),
+            }
+        }
+
+        pub fn new(constraint: XConstraintType<'a>, cost: XSoftCost, ub: Option<i64>) -> Self {
+            Self {
+                reification: None,
+                constraint: Box::new(constraint),
+                cost,
+                ub,
+            }
+        }
+
+        pub fn get_constraint(&self) -> &XConstraintType<'a> {
+            &self.constraint
+        }
+
+        pub fn get_constraint_mut(&mut self) -> &mut XConstraintType<'a> {
+            &mut self.constraint
+        }
+
+        pub fn get_cost(&self) -> &XSoftCost {
+            &self.cost
+        }
+
+        pub fn get_ub(&self) -> &Option<i64> {
+            &self.ub
+        }
+    }
+}
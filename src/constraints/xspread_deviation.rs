@@ -0,0 +1,211 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 14:10
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+    use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
+    use crate::data_structs::xrelational_operand::xcsp3_core::Operand;
+    use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::list_to_vec_var_val;
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::collections::HashMap;
+    use std::fmt::{Display, Formatter};
+
+    pub struct XSpreadDeviation<'a> {
+        scope: Vec<XVarVal>,
+        map: HashMap<String, &'a XDomainInteger>,
+        set: &'a XVariableSet,
+        total: Option<XVarVal>,
+        operator: Operator,
+        operand: Operand,
+        is_spread_or_deviation: bool, // true if spread, false if deviation
+        reification: Option<XReification>,
+    }
+
+    impl Display for XSpreadDeviation<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret: String;
+            if self.is_spread() {
+                ret = "XSpread: list =  ".to_string();
+            } else {
+                ret = "XDeviation: list =  ".to_string();
+            }
+            for e in self.scope.iter() {
+                ret.push('(');
+                ret.push_str(&e.to_string());
+                ret.push_str("), ")
+            }
+            if let Some(total) = &self.total {
+                ret.push_str(&format!(" total = {}", total));
+            }
+            ret.push_str(&format!(
+                " condition = ({:?}, {:?})",
+                self.operator, self.operand
+            ));
+            write!(f, "{}", ret)
+        }
+    }
+
+    impl XConstraintTrait for XSpreadDeviation<'_> {
+        fn get_scope_string(&self) -> &Vec<XVarVal> {
+            &self.scope
+        }
+
+        fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
+            for e in &self.scope {
+                if let XVarVal::IntVar(s) = e {
+                    if !self.map.contains_key(s) {
+                        if let Ok(vec) = self.set.construct_scope(&[s]) {
+                            for (vs, vv) in vec.into_iter() {
+                                self.map.insert(vs, vv);
+                            }
+                        }
+                    }
+                }
+            }
+            let mut scope_vec_var: Vec<(&String, &XDomainInteger)> = vec![];
+            for e in self.map.iter() {
+                scope_vec_var.push((e.0, e.1))
+            }
+            scope_vec_var
+        }
+    }
+
+    impl<'a> XSpreadDeviation<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
+        pub fn from_str(
+            list: &str,
+            total_str: &str,
+            condition: &str,
+            is_spread_or_deviation: bool,
+            set: &'a XVariableSet,
+        ) -> Result<Self, Xcsp3Error> {
+            let scope = list_to_vec_var_val(list)?;
+            let total = if total_str.trim().is_empty() {
+                None
+            } else {
+                match XVarVal::from_string(total_str.trim()) {
+                    Some(XVarVal::IntArgument(_)) | Some(XVarVal::IntStart) | None => {
+                        return Err(Xcsp3Error::get_constraint_spread_deviation_error(
+                            "parse spread/deviation constraint total error, ",
+                        ));
+                    }
+                    t => t,
+                }
+            };
+            let condition = condition.replace(['(', ')', ','], " ");
+            let spilt: Vec<&str> = condition.split_whitespace().collect();
+            let ope: Operator = match spilt.first().and_then(|o| Operator::get_operator_by_str(o)) {
+                None => {
+                    return Err(Xcsp3Error::get_constraint_spread_deviation_error(
+                        "parse spread/deviation constraint Operator error, ",
+                    ));
+                }
+                Some(o) => o,
+            };
+            let rand: Operand = match Operand::get_operand_by_str(&spilt[1..], &ope) {
+                None => {
+                    return Err(Xcsp3Error::get_constraint_spread_deviation_error(
+                        "parse spread/deviation constraint Operand error, ",
+                    ));
+                }
+                Some(r) => r,
+            };
+            Ok(Self::new(
+                scope,
+                set,
+                total,
+                ope,
+                rand,
+                is_spread_or_deviation,
+            ))
+        }
+
+        pub fn new(
+            scope: Vec<XVarVal>,
+            set: &'a XVariableSet,
+            total: Option<XVarVal>,
+            operator: Operator,
+            operand: Operand,
+            is_spread_or_deviation: bool,
+        ) -> Self {
+            Self {
+                reification: None,
+                scope,
+                map: Default::default(),
+                set,
+                total,
+                operator,
+                operand,
+                is_spread_or_deviation,
+            }
+        }
+
+        pub fn is_spread(&self) -> bool {
+            self.is_spread_or_deviation
+        }
+
+        pub fn is_deviation(&self) -> bool {
+            !self.is_spread_or_deviation
+        }
+
+        pub fn get_total(&self) -> &Option<XVarVal> {
+            &self.total
+        }
+
+        pub fn get_operand(&self) -> &Operand {
+            &self.operand
+        }
+
+        pub fn get_operator(&self) -> &Operator {
+            &self.operator
+        }
+    }
+}
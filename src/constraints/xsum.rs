@@ -41,6 +41,7 @@
 pub mod xcsp3_core {
     use crate::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::data_structs::xrelational_operand::xcsp3_core::Operand;
     use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
@@ -58,6 +59,7 @@ pub mod xcsp3_core {
         operator: Operator,
         operand: Operand,
         coeffs: Option<Vec<XVarVal>>,
+        reification: Option<XReification>,
     }
 
     impl Display for XSum<'_> {
@@ -110,6 +112,14 @@ pub mod xcsp3_core {
     }
 
     impl<'a> XSum<'a> {
+        pub fn get_reification(&self) -> &Option<XReification> {
+            &self.reification
+        }
+
+        pub fn set_reification(&mut self, reification: Option<XReification>) {
+            self.reification = reification
+        }
+
         pub fn from_str(
             list: &str,
             condition: &str,
@@ -160,6 +170,7 @@ pub mod xcsp3_core {
             coeffs: Option<Vec<XVarVal>>,
         ) -> Self {
             Self {
+                reification: None,
                 scope,
                 map: Default::default(),
                 set,
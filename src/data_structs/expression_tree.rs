@@ -39,13 +39,15 @@
  */
 pub mod xcsp3_utils {
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
+    use std::collections::HashMap;
     use std::fmt::{Display, Formatter};
     use std::str::FromStr;
 
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
     use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
 
-    #[derive(Debug, Clone)]
+    #[derive(Debug, Clone, PartialEq, Eq)]
     pub enum Operator {
         Add,
         Neg,
@@ -74,6 +76,36 @@ pub mod xcsp3_utils {
         If,
         Set,
         In,
+        // the operators on sets
+        Card,
+        Union,
+        Inter,
+        Diff,
+        Sdiff,
+        Hull,
+        Djoint,
+        Subset,
+        Subseq,
+        Supseq,
+        Supset,
+        Convex,
+        // the operators on reals
+        Fdiv,
+        Fmod,
+        Sqrt,
+        Nroot,
+        Exp,
+        Ln,
+        Log,
+        Sin,
+        Cos,
+        Tan,
+        Asin,
+        Acos,
+        Atan,
+        Sinh,
+        Cosh,
+        Tanh,
     }
 
     impl Operator {
@@ -106,16 +138,45 @@ pub mod xcsp3_utils {
                 "if" => Some(Operator::If),
                 "set" => Some(Operator::Set),
                 "in" => Some(Operator::In),
+                "card" => Some(Operator::Card),
+                "union" => Some(Operator::Union),
+                "inter" => Some(Operator::Inter),
+                "diff" => Some(Operator::Diff),
+                "sdiff" => Some(Operator::Sdiff),
+                "hull" => Some(Operator::Hull),
+                "djoint" => Some(Operator::Djoint),
+                "subset" => Some(Operator::Subset),
+                "subseq" => Some(Operator::Subseq),
+                "supseq" => Some(Operator::Supseq),
+                "supset" => Some(Operator::Supset),
+                "convex" => Some(Operator::Convex),
+                "fdiv" => Some(Operator::Fdiv),
+                "fmod" => Some(Operator::Fmod),
+                "sqrt" => Some(Operator::Sqrt),
+                "nroot" => Some(Operator::Nroot),
+                "exp" => Some(Operator::Exp),
+                "ln" => Some(Operator::Ln),
+                "log" => Some(Operator::Log),
+                "sin" => Some(Operator::Sin),
+                "cos" => Some(Operator::Cos),
+                "tan" => Some(Operator::Tan),
+                "asin" => Some(Operator::Asin),
+                "acos" => Some(Operator::Acos),
+                "atan" => Some(Operator::Atan),
+                "sinh" => Some(Operator::Sinh),
+                "cosh" => Some(Operator::Cosh),
+                "tanh" => Some(Operator::Tanh),
                 _ => None,
             }
         }
     }
 
-    #[derive(Clone, Debug)]
+    #[derive(Clone, Debug, PartialEq)]
     pub enum TreeNode {
         RightBracket,
         LeftBracket,
-        Constant(i32),
+        Constant(i64),
+        RealConstant(f64),
         Argument(i32),
         Variable(String),
         Operator(Operator, Vec<TreeNode>),
@@ -128,6 +189,7 @@ pub mod xcsp3_utils {
                 "{}",
                 match self {
                     TreeNode::Constant(i) => i.to_string(),
+                    TreeNode::RealConstant(r) => r.to_string(),
                     TreeNode::RightBracket => ")".to_string(),
                     TreeNode::LeftBracket => "(".to_string(),
                     TreeNode::Variable(v) => v.to_string(),
@@ -163,6 +225,640 @@ pub mod xcsp3_utils {
     //     }
     // }
 
+    /// the values of the variables and of the arguments used to evaluate an expression,
+    /// the argument %i is asked by its name, eg. "%0"
+    pub trait Assignment {
+        fn value_of(&self, id: &str) -> Option<i64>;
+    }
+
+    impl Assignment for HashMap<String, i64> {
+        fn value_of(&self, id: &str) -> Option<i64> {
+            self.get(id).copied()
+        }
+    }
+
+    impl<F: Fn(&str) -> Option<i64>> Assignment for F {
+        fn value_of(&self, id: &str) -> Option<i64> {
+            self(id)
+        }
+    }
+
+    impl TreeNode {
+        /// evaluate the node, the booleans are 0 (false) and 1 (true),
+        /// the operators on sets and reals can not be evaluated as integers
+        pub fn evaluate(&self, assignment: &dyn Assignment) -> Result<i64, Xcsp3Error> {
+            let error = |s: &str| Err(Xcsp3Error::get_constraint_evaluation_error(s));
+            let overflow = || Xcsp3Error::get_constraint_evaluation_error("integer overflow, ");
+            match self {
+                TreeNode::Constant(c) => Ok(*c),
+                TreeNode::Variable(v) => match assignment.value_of(v) {
+                    Some(n) => Ok(n),
+                    None => error(&format!("the variable {} is unbound, ", v)),
+                },
+                TreeNode::Argument(a) => match assignment.value_of(&format!("%{}", a)) {
+                    Some(n) => Ok(n),
+                    None => error(&format!("the argument %{} is unbound, ", a)),
+                },
+                TreeNode::RealConstant(r) => error(&format!(
+                    "the real {} can not be evaluated as an integer, ",
+                    r
+                )),
+                TreeNode::LeftBracket | TreeNode::RightBracket => {
+                    error("a bracket can not be evaluated, ")
+                }
+                TreeNode::Operator(ope, children) => {
+                    if let Operator::In = ope {
+                        return TreeNode::evaluate_in(children, assignment);
+                    }
+                    if let Operator::If = ope {
+                        if children.len() != 3 {
+                            return error("if needs three arguments, ");
+                        }
+                        return if children[0].evaluate(assignment)? != 0 {
+                            children[1].evaluate(assignment)
+                        } else {
+                            children[2].evaluate(assignment)
+                        };
+                    }
+                    let mut args: Vec<i64> = Vec::with_capacity(children.len());
+                    for c in children.iter() {
+                        args.push(c.evaluate(assignment)?);
+                    }
+                    let arity_error = |n: usize| {
+                        Err(Xcsp3Error::get_constraint_evaluation_error(&format!(
+                            "{:?} needs {} arguments, ",
+                            ope, n
+                        )))
+                    };
+                    let unary = |f: &dyn Fn(i64) -> Option<i64>| match args.as_slice() {
+                        [a] => f(*a).ok_or_else(overflow),
+                        _ => arity_error(1),
+                    };
+                    let binary =
+                        |f: &dyn Fn(i64, i64) -> Result<i64, Xcsp3Error>| match args.as_slice() {
+                            [a, b] => f(*a, *b),
+                            _ => arity_error(2),
+                        };
+                    let boolean = |b: bool| Ok(b as i64);
+                    match ope {
+                        Operator::Add => args
+                            .iter()
+                            .try_fold(0i64, |acc, a| acc.checked_add(*a))
+                            .ok_or_else(overflow),
+                        Operator::Mul => args
+                            .iter()
+                            .try_fold(1i64, |acc, a| acc.checked_mul(*a))
+                            .ok_or_else(overflow),
+                        Operator::Neg => unary(&|a| a.checked_neg()),
+                        Operator::Abs => unary(&|a| a.checked_abs()),
+                        Operator::Sqr => unary(&|a| a.checked_mul(a)),
+                        Operator::Not => unary(&|a| Some((a == 0) as i64)),
+                        Operator::Sub => binary(&|a, b| a.checked_sub(b).ok_or_else(overflow)),
+                        Operator::Dist => binary(&|a, b| {
+                            a.checked_sub(b)
+                                .and_then(|d| d.checked_abs())
+                                .ok_or_else(overflow)
+                        }),
+                        Operator::Div | Operator::Mod => binary(&|a, b| {
+                            if b == 0 {
+                                return error("division by zero, ");
+                            }
+                            match ope {
+                                Operator::Div => a.checked_div(b),
+                                _ => a.checked_rem(b),
+                            }
+                            .ok_or_else(overflow)
+                        }),
+                        Operator::Pow => binary(&|a, b| match u32::try_from(b) {
+                            Ok(e) => a.checked_pow(e).ok_or_else(overflow),
+                            Err(_) => error("the exponent of pow must be a non negative integer, "),
+                        }),
+                        Operator::Min | Operator::Max => {
+                            let m = match ope {
+                                Operator::Min => args.iter().min(),
+                                _ => args.iter().max(),
+                            };
+                            match m {
+                                Some(m) => Ok(*m),
+                                None => error(&format!("{:?} needs at least one argument, ", ope)),
+                            }
+                        }
+                        Operator::Lt => binary(&|a, b| boolean(a < b)),
+                        Operator::Le => binary(&|a, b| boolean(a <= b)),
+                        Operator::Ge => binary(&|a, b| boolean(a >= b)),
+                        Operator::Gt => binary(&|a, b| boolean(a > b)),
+                        Operator::Imp => binary(&|a, b| boolean(a == 0 || b != 0)),
+                        Operator::Eq => boolean(args.windows(2).all(|w| w[0] == w[1])),
+                        Operator::Ne => boolean(
+                            (0..args.len()).all(|i| args[i + 1..].iter().all(|b| *b != args[i])),
+                        ),
+                        Operator::And => boolean(args.iter().all(|a| *a != 0)),
+                        Operator::Or => boolean(args.iter().any(|a| *a != 0)),
+                        Operator::Xor => boolean(args.iter().filter(|a| **a != 0).count() % 2 == 1),
+                        Operator::Iff => {
+                            boolean(args.windows(2).all(|w| (w[0] != 0) == (w[1] != 0)))
+                        }
+                        Operator::Set => error("a set can only be evaluated in the operator in, "),
+                        Operator::If | Operator::In => unreachable!(),
+                        Operator::Card
+                        | Operator::Union
+                        | Operator::Inter
+                        | Operator::Diff
+                        | Operator::Sdiff
+                        | Operator::Hull
+                        | Operator::Djoint
+                        | Operator::Subset
+                        | Operator::Subseq
+                        | Operator::Supseq
+                        | Operator::Supset
+                        | Operator::Convex => error(&format!(
+                            "the set operator {:?} can not be evaluated as an integer, ",
+                            ope
+                        )),
+                        Operator::Fdiv
+                        | Operator::Fmod
+                        | Operator::Sqrt
+                        | Operator::Nroot
+                        | Operator::Exp
+                        | Operator::Ln
+                        | Operator::Log
+                        | Operator::Sin
+                        | Operator::Cos
+                        | Operator::Tan
+                        | Operator::Asin
+                        | Operator::Acos
+                        | Operator::Atan
+                        | Operator::Sinh
+                        | Operator::Cosh
+                        | Operator::Tanh => error(&format!(
+                            "the real operator {:?} can not be evaluated as an integer, ",
+                            ope
+                        )),
+                    }
+                }
+            }
+        }
+
+        /// in(x, set(a, b, ...)) is true if x is one of the values of the set
+        fn evaluate_in(
+            children: &[TreeNode],
+            assignment: &dyn Assignment,
+        ) -> Result<i64, Xcsp3Error> {
+            match children {
+                [x, TreeNode::Operator(Operator::Set, values)] => {
+                    let x = x.evaluate(assignment)?;
+                    for v in values.iter() {
+                        if v.evaluate(assignment)? == x {
+                            return Ok(1);
+                        }
+                    }
+                    Ok(0)
+                }
+                _ => Err(Xcsp3Error::get_constraint_evaluation_error(
+                    "in needs a value and a set, ",
+                )),
+            }
+        }
+
+        /// return the simplified node, the constant subtrees are folded, the neutral elements are removed,
+        /// the nested add, mul, and, or are flattened and the arguments are in a canonical order
+        pub fn simplify(&self) -> TreeNode {
+            match self {
+                TreeNode::Operator(ope, children) => TreeNode::simplify_operator(
+                    ope.clone(),
+                    children.iter().map(|c| c.simplify()).collect(),
+                ),
+                _ => self.clone(),
+            }
+        }
+
+        fn simplify_operator(ope: Operator, children: Vec<TreeNode>) -> TreeNode {
+            let mut children = children;
+            let associative = matches!(
+                ope,
+                Operator::Add | Operator::Mul | Operator::And | Operator::Or
+            );
+            if associative {
+                let mut flat: Vec<TreeNode> = Vec::with_capacity(children.len());
+                for c in children.into_iter() {
+                    match c {
+                        TreeNode::Operator(o, sub) if o == ope => flat.extend(sub),
+                        _ => flat.push(c),
+                    }
+                }
+                children = flat;
+            }
+            let is_constant = |c: &TreeNode| match c {
+                TreeNode::Constant(_) => true,
+                TreeNode::Operator(Operator::Set, values) => {
+                    values.iter().all(|v| matches!(v, TreeNode::Constant(_)))
+                }
+                _ => false,
+            };
+            if !matches!(ope, Operator::Set) && children.iter().all(is_constant) {
+                let node = TreeNode::Operator(ope, children);
+                return match node.evaluate(&|_: &str| None) {
+                    Ok(n) => TreeNode::Constant(n),
+                    Err(_) => node,
+                };
+            }
+            if associative {
+                return TreeNode::simplify_associative(ope, children);
+            }
+            match (&ope, children.as_slice()) {
+                (Operator::Sub, [a, TreeNode::Constant(0)]) => a.clone(),
+                (Operator::Sub, [TreeNode::Constant(0), b]) => {
+                    TreeNode::simplify_operator(Operator::Neg, vec![b.clone()])
+                }
+                (Operator::Sub, [a, b]) if a == b => TreeNode::Constant(0),
+                (Operator::Neg, [TreeNode::Operator(Operator::Neg, x)]) if x.len() == 1 => {
+                    x[0].clone()
+                }
+                (Operator::Not, [TreeNode::Operator(Operator::Not, x)])
+                    if x.len() == 1 && x[0].is_boolean() =>
+                {
+                    x[0].clone()
+                }
+                (Operator::Abs, [TreeNode::Operator(Operator::Abs | Operator::Neg, x)]) => {
+                    TreeNode::Operator(Operator::Abs, x.clone())
+                }
+                (Operator::Div | Operator::Pow, [a, TreeNode::Constant(1)]) => a.clone(),
+                (Operator::Mod, [_, TreeNode::Constant(1)]) => TreeNode::Constant(0),
+                (Operator::Pow, [_, TreeNode::Constant(0)]) => TreeNode::Constant(1),
+                (Operator::If, [TreeNode::Constant(c), a, b]) => {
+                    if *c != 0 {
+                        a.clone()
+                    } else {
+                        b.clone()
+                    }
+                }
+                (Operator::Min | Operator::Max, [a]) => a.clone(),
+                (Operator::Lt | Operator::Le | Operator::Ge | Operator::Gt, [a, b])
+                    if a.canonical_key() > b.canonical_key() =>
+                {
+                    let mirror = match ope {
+                        Operator::Lt => Operator::Gt,
+                        Operator::Le => Operator::Ge,
+                        Operator::Ge => Operator::Le,
+                        _ => Operator::Lt,
+                    };
+                    TreeNode::Operator(mirror, vec![b.clone(), a.clone()])
+                }
+                _ => {
+                    if matches!(
+                        ope,
+                        Operator::Xor
+                            | Operator::Iff
+                            | Operator::Eq
+                            | Operator::Ne
+                            | Operator::Min
+                            | Operator::Max
+                            | Operator::Dist
+                    ) {
+                        children.sort_by_cached_key(|c| c.canonical_key());
+                    }
+                    TreeNode::Operator(ope, children)
+                }
+            }
+        }
+
+        /// fold the constants of add, mul, and, or, and remove their neutral elements
+        fn simplify_associative(ope: Operator, children: Vec<TreeNode>) -> TreeNode {
+            let (neutral, absorbing): (i64, Option<i64>) = match ope {
+                Operator::Add => (0, None),
+                Operator::Mul => (1, Some(0)),
+                Operator::And => (1, Some(0)),
+                _ => (0, Some(1)),
+            };
+            let mut constant = neutral;
+            let mut others: Vec<TreeNode> = Vec::with_capacity(children.len());
+            for c in children.into_iter() {
+                match c {
+                    TreeNode::Constant(n) => {
+                        let folded = match ope {
+                            Operator::Add => constant.checked_add(n),
+                            Operator::Mul => constant.checked_mul(n),
+                            Operator::And => Some((constant != 0 && n != 0) as i64),
+                            _ => Some((constant != 0 || n != 0) as i64),
+                        };
+                        match folded {
+                            Some(f) => constant = f,
+                            None => others.push(TreeNode::Constant(n)),
+                        }
+                    }
+                    _ => others.push(c),
+                }
+            }
+            if Some(constant) == absorbing {
+                return TreeNode::Constant(constant);
+            }
+            if constant != neutral {
+                others.push(TreeNode::Constant(constant));
+            }
+            match others.len() {
+                0 => TreeNode::Constant(neutral),
+                1 if matches!(ope, Operator::Add | Operator::Mul) || others[0].is_boolean() => {
+                    others.pop().unwrap()
+                }
+                _ => {
+                    others.sort_by_cached_key(|c| c.canonical_key());
+                    TreeNode::Operator(ope, others)
+                }
+            }
+        }
+
+        /// return the interval [min, max] of the values the node can take with the domains of the variables,
+        /// computed by interval arithmetic, the booleans are in [0, 1]
+        pub fn bounds(&self, set: &XVariableSet) -> Result<(i64, i64), Xcsp3Error> {
+            let error = |s: &str| Err(Xcsp3Error::get_constraint_evaluation_error(s));
+            match self {
+                TreeNode::Constant(c) => Ok((*c, *c)),
+                TreeNode::Variable(v) => match set.construct_scope(&[v]) {
+                    Ok(scope) => match scope.first() {
+                        Some((_, d)) => match (d.minimum(), d.maximum()) {
+                            (Some(min), Some(max)) => Ok((min, max)),
+                            _ => error(&format!("the domain of {} is empty, ", v)),
+                        },
+                        None => error(&format!("the domain of {} is empty, ", v)),
+                    },
+                    Err(e) => Err(e),
+                },
+                TreeNode::Argument(a) => error(&format!("the argument %{} has no bounds, ", a)),
+                TreeNode::RealConstant(r) => {
+                    error(&format!("the real {} has no integer bounds, ", r))
+                }
+                TreeNode::LeftBracket | TreeNode::RightBracket => {
+                    error("a bracket has no bounds, ")
+                }
+                TreeNode::Operator(ope, children) => {
+                    if self.is_boolean() {
+                        return Ok((0, 1));
+                    }
+                    let mut args: Vec<(i128, i128)> = Vec::with_capacity(children.len());
+                    for c in children.iter() {
+                        let (min, max) = c.bounds(set)?;
+                        args.push((min as i128, max as i128));
+                    }
+                    let arity_error = |n: usize| {
+                        Err(Xcsp3Error::get_constraint_evaluation_error(&format!(
+                            "{:?} needs {} arguments, ",
+                            ope, n
+                        )))
+                    };
+                    let clamp = |(min, max): (i128, i128)| {
+                        Ok((
+                            min.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+                            max.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+                        ))
+                    };
+                    let abs = |(min, max): (i128, i128)| {
+                        if min >= 0 {
+                            (min, max)
+                        } else if max <= 0 {
+                            (-max, -min)
+                        } else {
+                            (0, max.max(-min))
+                        }
+                    };
+                    match (ope, args.as_slice()) {
+                        (Operator::Add, _) => clamp(args.iter().fold((0, 0), |acc, a| {
+                            (saturate(acc.0 + a.0), saturate(acc.1 + a.1))
+                        })),
+                        (Operator::Mul, _) => clamp(args.iter().fold((1, 1), |acc, a| {
+                            hull(&[acc.0 * a.0, acc.0 * a.1, acc.1 * a.0, acc.1 * a.1])
+                        })),
+                        (Operator::Sub, [a, b]) => clamp((a.0 - b.1, a.1 - b.0)),
+                        (Operator::Neg, [a]) => clamp((-a.1, -a.0)),
+                        (Operator::Abs, [a]) => clamp(abs(*a)),
+                        (Operator::Sqr, [a]) => {
+                            let (min, max) = abs(*a);
+                            clamp((saturate(min * min), saturate(max * max)))
+                        }
+                        (Operator::Dist, [a, b]) => clamp(abs((a.0 - b.1, a.1 - b.0))),
+                        (Operator::Div, [a, b]) => {
+                            // the divisor is split into its negative and positive parts
+                            let mut candidates: Vec<i128> = vec![];
+                            for (min, max) in [(b.0, b.1.min(-1)), (b.0.max(1), b.1)] {
+                                if min <= max {
+                                    for d in [min, max] {
+                                        candidates.extend([a.0 / d, a.1 / d]);
+                                    }
+                                }
+                            }
+                            if candidates.is_empty() {
+                                return error("division by zero, ");
+                            }
+                            clamp(hull(&candidates))
+                        }
+                        (Operator::Mod, [a, b]) => {
+                            if b.0 == 0 && b.1 == 0 {
+                                return error("division by zero, ");
+                            }
+                            // the remainder has the sign of the dividend and is less than the divisor
+                            let m = b.0.abs().max(b.1.abs()) - 1;
+                            // the smallest absolute value of a non zero divisor
+                            let d = if b.0 > 0 || b.1 < 0 {
+                                b.0.abs().min(b.1.abs())
+                            } else {
+                                1
+                            };
+                            if (a.0 >= 0 && a.1 < d) || (a.1 <= 0 && a.0 > -d) {
+                                // the dividend is never changed
+                                clamp(*a)
+                            } else {
+                                clamp((a.0.max(-m).min(0), a.1.min(m).max(0)))
+                            }
+                        }
+                        (Operator::Pow, [a, b]) => {
+                            if b.1 < 0 {
+                                return error(
+                                    "the exponent of pow must be a non negative integer, ",
+                                );
+                            }
+                            let (emin, emax) = (b.0.max(0), b.1);
+                            let mut bases = vec![a.0, a.1];
+                            bases.extend([-1, 0, 1].iter().filter(|v| a.0 <= **v && **v <= a.1));
+                            let mut candidates: Vec<i128> = vec![];
+                            for e in [emin, emin + 1, emax - 1, emax] {
+                                if emin <= e && e <= emax {
+                                    for base in bases.iter() {
+                                        candidates.push(power(*base, e));
+                                    }
+                                }
+                            }
+                            clamp(hull(&candidates))
+                        }
+                        (Operator::Min, [_, ..]) => clamp((
+                            args.iter().map(|a| a.0).min().unwrap_or_default(),
+                            args.iter().map(|a| a.1).min().unwrap_or_default(),
+                        )),
+                        (Operator::Max, [_, ..]) => clamp((
+                            args.iter().map(|a| a.0).max().unwrap_or_default(),
+                            args.iter().map(|a| a.1).max().unwrap_or_default(),
+                        )),
+                        (Operator::If, [c, a, b]) => {
+                            if c.0 == 0 && c.1 == 0 {
+                                clamp(*b)
+                            } else if c.0 > 0 || c.1 < 0 {
+                                clamp(*a)
+                            } else {
+                                clamp((a.0.min(b.0), a.1.max(b.1)))
+                            }
+                        }
+                        (
+                            Operator::Sub
+                            | Operator::Dist
+                            | Operator::Div
+                            | Operator::Mod
+                            | Operator::Pow,
+                            _,
+                        ) => arity_error(2),
+                        (Operator::Neg | Operator::Abs | Operator::Sqr, _) => arity_error(1),
+                        (Operator::If, _) => arity_error(3),
+                        _ => error(&format!("the operator {:?} has no integer bounds, ", ope)),
+                    }
+                }
+            }
+        }
+
+        /// return the linear form sum(coef * x) + constant of the node,
+        /// eg. add(mul(3,x),sub(y,2)) -> ([(x,3),(y,1)], -2), None if the node is not linear
+        pub fn to_linear(&self) -> Option<(Vec<(String, i64)>, i64)> {
+            match self {
+                TreeNode::Variable(v) => Some((vec![(v.clone(), 1)], 0)),
+                TreeNode::Constant(c) => Some((vec![], *c)),
+                TreeNode::Operator(ope, children) => match (ope, children.as_slice()) {
+                    (Operator::Add, _) => {
+                        let mut ret: (Vec<(String, i64)>, i64) = (vec![], 0);
+                        for c in children.iter() {
+                            ret = TreeNode::add_linear(ret, c.to_linear()?, 1)?;
+                        }
+                        Some(ret)
+                    }
+                    (Operator::Sub, [a, b]) => {
+                        TreeNode::add_linear(a.to_linear()?, b.to_linear()?, -1)
+                    }
+                    (Operator::Neg, [a]) => TreeNode::add_linear((vec![], 0), a.to_linear()?, -1),
+                    (Operator::Mul, _) => {
+                        let mut ret: (Vec<(String, i64)>, i64) = (vec![], 1);
+                        for c in children.iter() {
+                            let (terms, constant) = c.to_linear()?;
+                            ret = match (ret.0.is_empty(), terms.is_empty()) {
+                                (_, true) => TreeNode::add_linear((vec![], 0), ret, constant)?,
+                                (true, false) => {
+                                    TreeNode::add_linear((vec![], 0), (terms, constant), ret.1)?
+                                }
+                                (false, false) => return None,
+                            };
+                        }
+                        Some(ret)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+
+        /// return a + factor * b
+        fn add_linear(
+            a: (Vec<(String, i64)>, i64),
+            b: (Vec<(String, i64)>, i64),
+            factor: i64,
+        ) -> Option<(Vec<(String, i64)>, i64)> {
+            let (mut terms, constant) = a;
+            for (x, coef) in b.0.into_iter() {
+                let coef = coef.checked_mul(factor)?;
+                match terms.iter_mut().find(|(y, _)| *y == x) {
+                    Some((_, c)) => *c = c.checked_add(coef)?,
+                    None => terms.push((x, coef)),
+                }
+            }
+            terms.retain(|(_, c)| *c != 0);
+            Some((terms, constant.checked_add(b.1.checked_mul(factor)?)?))
+        }
+
+        /// return true if the node is a relational or logical operator, whose value is 0 or 1
+        pub fn is_boolean(&self) -> bool {
+            matches!(
+                self,
+                TreeNode::Operator(
+                    Operator::Lt
+                        | Operator::Le
+                        | Operator::Ge
+                        | Operator::Gt
+                        | Operator::Ne
+                        | Operator::Eq
+                        | Operator::And
+                        | Operator::Not
+                        | Operator::Or
+                        | Operator::Xor
+                        | Operator::Iff
+                        | Operator::Imp
+                        | Operator::In,
+                    _
+                )
+            )
+        }
+
+        /// the key of the canonical order: the variables, the arguments, the operators and the constants
+        fn canonical_key(&self) -> (u8, String) {
+            match self {
+                TreeNode::Variable(v) => (0, v.clone()),
+                TreeNode::Argument(a) => (1, format!("{:010}", a)),
+                TreeNode::Operator(_, _) => (2, self.to_expression()),
+                TreeNode::Constant(c) => (3, format!("{:+020}", c)),
+                TreeNode::RealConstant(r) => (4, r.to_string()),
+                _ => (5, String::default()),
+            }
+        }
+
+        /// return the node written in the XCSP3 format, eg. add(x,mul(y,2))
+        pub fn to_expression(&self) -> String {
+            match self {
+                TreeNode::Operator(ope, children) => format!(
+                    "{}({})",
+                    format!("{:?}", ope).to_lowercase(),
+                    children
+                        .iter()
+                        .map(|c| c.to_expression())
+                        .collect::<Vec<String>>()
+                        .join(",")
+                ),
+                _ => self.to_string(),
+            }
+        }
+    }
+
+    /// keep the value in the range of i64, so the next operations on i128 can not overflow
+    fn saturate(value: i128) -> i128 {
+        value.clamp(i64::MIN as i128, i64::MAX as i128)
+    }
+
+    /// the interval [min, max] of the values
+    fn hull(values: &[i128]) -> (i128, i128) {
+        let min = values.iter().copied().min().unwrap_or_default();
+        let max = values.iter().copied().max().unwrap_or_default();
+        (saturate(min), saturate(max))
+    }
+
+    /// base^exponent, saturated to the range of i64
+    fn power(base: i128, exponent: i128) -> i128 {
+        match u32::try_from(exponent) {
+            Ok(e) => match base.checked_pow(e) {
+                Some(p) => saturate(p),
+                None if base < 0 && e % 2 == 1 => i64::MIN as i128,
+                None => i64::MAX as i128,
+            },
+            Err(_) => match base {
+                0 | 1 => base,
+                -1 if exponent % 2 == 0 => 1,
+                -1 => -1,
+                _ if base < 0 && exponent % 2 == 1 => i64::MIN as i128,
+                _ => i64::MAX as i128,
+            },
+        }
+    }
+
     #[derive(Clone)]
     pub struct ExpressionTree {
         root: TreeNode,
@@ -178,6 +874,7 @@ pub mod xcsp3_utils {
                     TreeNode::Variable(_) => ret += ",",
                     TreeNode::Argument(_) => ret += ",",
                     TreeNode::Constant(_) => ret += ",",
+                    TreeNode::RealConstant(_) => ret += ",",
                     _ => {}
                 }
             }
@@ -185,6 +882,28 @@ pub mod xcsp3_utils {
         }
     }
     impl ExpressionTree {
+        /// evaluate the expression with the values of the variables and arguments,
+        /// given by a HashMap<String, i64> or a closure Fn(&str) -> Option<i64>
+        pub fn evaluate(&self, assignment: &dyn Assignment) -> Result<i64, Xcsp3Error> {
+            self.root.evaluate(assignment)
+        }
+
+        /// return a new simplified expression, eg. add(x,0,mul(1,y)) -> add(x,y)
+        pub fn simplify(&self) -> ExpressionTree {
+            ExpressionTree {
+                root: self.root.simplify(),
+            }
+        }
+
+        /// return the interval [min, max] of the values of the expression with the domains of the variables
+        pub fn bounds(&self, set: &XVariableSet) -> Result<(i64, i64), Xcsp3Error> {
+            self.root.bounds(set)
+        }
+
+        pub fn get_root(&self) -> &TreeNode {
+            &self.root
+        }
+
         pub fn get_scope(&self) -> Vec<String> {
             let mut scope = vec![];
             for e in self.first_order_iter() {
@@ -218,6 +937,72 @@ pub mod xcsp3_utils {
             scope
         }
 
+        /// replace the symbols by their values, eg. eq(x,red) -> eq(x,0),
+        /// a symbol is mapped by the domain of the variable it is compared to, eg. x in eq(x,red) or in(x,set(red,blue)),
+        /// otherwise by the symbolic domains of the scope if they all give it the same value
+        pub fn replace_symbolic(&mut self, set: &XVariableSet) {
+            let mut domains: Vec<&XDomainInteger> = vec![];
+            for v in self.get_scope().iter() {
+                if let Ok(scope) = set.construct_scope(&[v]) {
+                    for (_, d) in scope.into_iter() {
+                        if d.contain_symbol() {
+                            domains.push(d);
+                        }
+                    }
+                }
+            }
+            if !domains.is_empty() {
+                ExpressionTree::replace_symbolic_node(&mut self.root, set, None, &domains);
+            }
+        }
+
+        fn replace_symbolic_node(
+            node: &mut TreeNode,
+            set: &XVariableSet,
+            compared: Option<&XDomainInteger>,
+            domains: &[&XDomainInteger],
+        ) {
+            let value = match node {
+                TreeNode::Operator(_, children) => {
+                    let compared = children
+                        .iter()
+                        .find_map(|c| match c {
+                            TreeNode::Variable(v) => match set.construct_scope(&[v]) {
+                                Ok(scope) => scope
+                                    .into_iter()
+                                    .map(|(_, d)| d)
+                                    .find(|d| d.contain_symbol()),
+                                Err(_) => None,
+                            },
+                            _ => None,
+                        })
+                        .or(compared);
+                    for c in children.iter_mut() {
+                        ExpressionTree::replace_symbolic_node(c, set, compared, domains);
+                    }
+                    None
+                }
+                TreeNode::Variable(v) if set.find_variable(v).is_err() => match compared {
+                    Some(d) => Some(d.find_symbolic(v)).filter(|n| *n != usize::MAX),
+                    None => {
+                        let values: Vec<usize> = domains
+                            .iter()
+                            .map(|d| d.find_symbolic(v))
+                            .filter(|n| *n != usize::MAX)
+                            .collect();
+                        match values.first() {
+                            Some(n) if values.iter().all(|m| m == n) => Some(*n),
+                            _ => None,
+                        }
+                    }
+                },
+                _ => None,
+            };
+            if let Some(n) = value {
+                *node = TreeNode::Constant(n as i64);
+            }
+        }
+
         pub fn from_string(expression: &str) -> Result<Self, Xcsp3Error> {
             match ExpressionTree::parse(expression) {
                 Ok(e) => Ok(ExpressionTree {
@@ -243,9 +1028,12 @@ pub mod xcsp3_utils {
                             }
                         }
                     } else {
-                        match i32::from_str(&expression[..]) {
+                        match i64::from_str(&expression[..]) {
                             Ok(n) => stack.push(TreeNode::Constant(n)),
-                            Err(_) => stack.push(TreeNode::Variable(expression)),
+                            Err(_) => match ExpressionTree::parse_real(&expression) {
+                                Some(r) => stack.push(TreeNode::RealConstant(r)),
+                                None => stack.push(TreeNode::Variable(expression)),
+                            },
                         }
                     }
                 }
@@ -275,6 +1063,15 @@ pub mod xcsp3_utils {
             None
         }
 
+        /// a real constant starts with a digit or a minus, eg. 1.5 or -2e3
+        fn parse_real(expression: &str) -> Option<f64> {
+            if expression.starts_with(|c: char| c.is_ascii_digit() || c == '-') {
+                f64::from_str(expression).ok().filter(|r| r.is_finite())
+            } else {
+                None
+            }
+        }
+
         fn parse(expression: &str) -> Result<TreeNode, Xcsp3Error> {
             let mut stack: Vec<TreeNode> = vec![];
             let exp: String = expression.chars().filter(|c| !c.is_whitespace()).collect();
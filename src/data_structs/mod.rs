@@ -15,5 +15,6 @@
 
 pub mod expression_tree;
 pub mod xint_val_var;
+pub mod xreification;
 pub mod xrelational_operand;
 pub mod xrelational_operator;
@@ -23,8 +23,9 @@ pub mod xcsp3_core {
     pub enum XVarVal {
         // IntVar(Variable<'a>),
         IntVar(String),
-        IntVal(i32),
-        IntInterval(i32, i32),
+        IntVal(i64),
+        RealVal(f64),
+        IntInterval(i64, i64),
         IntArgument(i32),
         IntStart,
         IntNone,
@@ -42,6 +43,9 @@ pub mod xcsp3_core {
                     XVarVal::IntVal(v) => {
                         v.to_string()
                     }
+                    XVarVal::RealVal(v) => {
+                        v.to_string()
+                    }
                     XVarVal::IntInterval(l, r) => {
                         format!("{}..{}", l, r)
                     }
@@ -81,9 +85,17 @@ pub mod xcsp3_core {
                     None
                 }
             } else {
-                match i32::from_str(s) {
+                match i64::from_str(s) {
                     Ok(e) => Some(XVarVal::IntVal(e)),
-                    Err(_) => Some(XVarVal::IntVar(String::from(s))),
+                    Err(_) => match f64::from_str(s) {
+                        Ok(r)
+                            if r.is_finite()
+                                && s.starts_with(|c: char| c.is_ascii_digit() || c == '-') =>
+                        {
+                            Some(XVarVal::RealVal(r))
+                        }
+                        _ => Some(XVarVal::IntVar(String::from(s))),
+                    },
                 }
                 // match i32::from_str(s) {
                 //     Ok(e) => Some(XVarVal::IntVal(e)),
@@ -0,0 +1,99 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 16:20
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum XReificationType {
+        /// b <-> c
+        ReifiedBy,
+        /// b -> c
+        HReifiedFrom,
+        /// c -> b
+        HReifiedTo,
+    }
+
+    /// the reification of a constraint by a 0/1 variable
+    #[derive(Clone, Debug)]
+    pub struct XReification {
+        kind: XReificationType,
+        var: String,
+    }
+
+    impl Display for XReification {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}({})", self.kind, self.var)
+        }
+    }
+
+    impl XReification {
+        /// at most one of the three attributes is given, return None if there is no reification
+        pub fn from_str(reified_by: &str, hreified_from: &str, hreified_to: &str) -> Option<Self> {
+            if !reified_by.trim().is_empty() {
+                Some(Self::new(XReificationType::ReifiedBy, reified_by.trim()))
+            } else if !hreified_from.trim().is_empty() {
+                Some(Self::new(
+                    XReificationType::HReifiedFrom,
+                    hreified_from.trim(),
+                ))
+            } else if !hreified_to.trim().is_empty() {
+                Some(Self::new(XReificationType::HReifiedTo, hreified_to.trim()))
+            } else {
+                None
+            }
+        }
+
+        pub fn new(kind: XReificationType, var: &str) -> Self {
+            Self {
+                kind,
+                var: var.to_string(),
+            }
+        }
+
+        pub fn get_kind(&self) -> &XReificationType {
+            &self.kind
+        }
+
+        pub fn get_var(&self) -> &String {
+            &self.var
+        }
+    }
+}
@@ -40,15 +40,18 @@
 
 pub mod xcsp3_core {
     use crate::data_structs::xrelational_operator::xcsp3_core::Operator;
+    use crate::utils::utils_functions::xcsp3_utils::str_to_bound;
+    use crate::variables::xdomain::xcsp3_core::XBound;
     use std::collections::HashSet;
     use std::str::FromStr;
 
     #[derive(Clone, Debug)]
     pub enum Operand {
-        Integer(i32),
+        Integer(i64),
         Variable(String),
-        Interval(i32, i32),
-        SetInteger(HashSet<i32>),
+        /// the bounds can be infinite, eg. 0..+infinity
+        Interval(XBound, XBound),
+        SetInteger(HashSet<i64>),
     }
 
     impl Operand {
@@ -59,10 +62,14 @@ pub mod xcsp3_core {
                 Operator::Notin => is_set = true,
                 _ => {}
             }
+            // the interval of in can be infinite, eg. (in,0..+infinity)
+            if s.len() == 1 && s[0].contains("..") {
+                is_set = false;
+            }
             return if is_set {
-                let mut ret: HashSet<i32> = HashSet::new();
+                let mut ret: HashSet<i64> = HashSet::new();
                 for l in s.iter() {
-                    match i32::from_str(l) {
+                    match i64::from_str(l) {
                         Ok(n) => {
                             ret.insert(n);
                         }
@@ -77,24 +84,24 @@ pub mod xcsp3_core {
             } else if s[0].contains("..") {
                 let interval: Vec<&str> = s[0].split("..").collect();
                 if interval.len() == 2 {
-                    match i32::from_str(interval[0]) {
-                        Ok(l) => match i32::from_str(interval[1]) {
-                            Ok(r) => {
+                    match str_to_bound(interval[0]) {
+                        Some(l) => match str_to_bound(interval[1]) {
+                            Some(r) => {
                                 if l <= r {
                                     Some(Operand::Interval(l, r))
                                 } else {
                                     None
                                 }
                             }
-                            Err(_) => None,
+                            None => None,
                         },
-                        Err(_) => None,
+                        None => None,
                     }
                 } else {
                     None
                 }
             } else {
-                match i32::from_str(s[0]) {
+                match i64::from_str(s[0]) {
                     Ok(n) => Some(Operand::Integer(n)),
                     Err(_) => Some(Operand::Variable(s[0].to_string())),
                 }
@@ -40,7 +40,9 @@
 
 pub mod xcsp3error;
 
+mod parse_annotations_error;
 mod parse_constraint_error;
 mod parse_domain_error;
+mod parse_framework_error;
 mod parse_objectives_error;
 mod parse_variable_error;
@@ -91,6 +91,112 @@ pub mod xcsp3_core {
             }
         }
 
+        pub(crate) fn get_circuit_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/graph/circuit/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::CircuitError,
+            }
+        }
+
+        pub(crate) fn get_lex_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/comparison/lex/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::LexError,
+            }
+        }
+
+        pub(crate) fn get_precedence_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/connection/precedence/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::PrecedenceError,
+            }
+        }
+
+        pub(crate) fn get_bin_packing_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/packing-scheduling/binpacking/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::BinPackingError,
+            }
+        }
+
+        pub(crate) fn get_clause_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/generic/clause/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::ClauseError,
+            }
+        }
+
+        pub(crate) fn get_all_distant_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/comparison/alldistant/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::AllDistantError,
+            }
+        }
+
+        pub(crate) fn get_balance_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/counting-summing/balance/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::BalanceError,
+            }
+        }
+
+        pub(crate) fn get_spread_deviation_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/counting-summing/spread/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::SpreadDeviationError,
+            }
+        }
+
+        pub(crate) fn get_knapsack_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/counting-summing/knapsack/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::KnapsackError,
+            }
+        }
+
+        pub(crate) fn get_flow_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/connection/flow/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::FlowError,
+            }
+        }
+
+        pub(crate) fn get_meta_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str = " please visit http://xcsp.org/specifications/constraints/meta/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::MetaError,
+            }
+        }
+
+        pub(crate) fn get_soft_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str = " please visit http://xcsp.org/specifications/frameworks/wcsp/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::SoftError,
+            }
+        }
+
         pub(crate) fn get_element_error(s: &str) -> ParseConstraintError {
             const WEBSITE: &str =
                 " please visit http://xcsp.org/specifications/constraints/connection/element/";
@@ -157,6 +263,24 @@ pub mod xcsp3_core {
                 r#type: ConstraintError::GroupError,
             }
         }
+        pub(crate) fn get_evaluation_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/generic/intension/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::EvaluationError,
+            }
+        }
+
+        pub(crate) fn get_linear_error(s: &str) -> ParseConstraintError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/constraints/counting-summing/sum/";
+            ParseConstraintError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ConstraintError::LinearError,
+            }
+        }
+
         pub(crate) fn get_expression_error(s: &str) -> ParseConstraintError {
             const WEBSITE: &str =
                 " please visit http://xcsp.org/specifications/constraints/generic/intension/";
@@ -190,5 +314,19 @@ pub mod xcsp3_core {
         ChannelError,
         NoOverlapError,
         CumulativeError,
+        CircuitError,
+        LexError,
+        PrecedenceError,
+        BinPackingError,
+        ClauseError,
+        AllDistantError,
+        BalanceError,
+        SpreadDeviationError,
+        KnapsackError,
+        FlowError,
+        MetaError,
+        SoftError,
+        EvaluationError,
+        LinearError,
     }
 }
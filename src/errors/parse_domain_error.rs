@@ -46,6 +46,8 @@ pub mod xcsp3_core {
         UnknownInteger,
         UnknownInterval,
         UnknownFor,
+        UnknownReal,
+        UnknownSymbolic,
     }
 
     #[derive(Debug, Clone, PartialEq, Eq)]
@@ -70,6 +72,21 @@ pub mod xcsp3_core {
                 r#type: DomainError::UnknownInteger,
             }
         }
+        pub(crate) fn get_real_error(s: &str) -> ParseDomainError {
+            const WEBSITE: &str = " please visit http://xcsp.org/specifications/variables/real/";
+            ParseDomainError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: DomainError::UnknownReal,
+            }
+        }
+        pub(crate) fn get_symbolic_error(s: &str) -> ParseDomainError {
+            const WEBSITE: &str =
+                " please visit http://xcsp.org/specifications/variables/symbolic/";
+            ParseDomainError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: DomainError::UnknownSymbolic,
+            }
+        }
         pub(crate) fn get_interval_error(s: &str) -> ParseDomainError {
             const WEBSITE: &str = " please visit http://xcsp.org/specifications/variables/arrays/";
             ParseDomainError {
@@ -46,6 +46,7 @@ pub mod xcsp3_core {
         UnknownCoeffs,
         UnknownType,
         UnknownTarget,
+        UnknownCombination,
     }
 
     #[derive(Debug, Clone, PartialEq, Eq)]
@@ -85,6 +86,14 @@ pub mod xcsp3_core {
             }
         }
 
+        pub(crate) fn get_combination_error(s: &str) -> ParseObjectivesError {
+            const WEBSITE: &str = " please visit http://xcsp.org/specifications/objectives/";
+            ParseObjectivesError {
+                msg: (s.to_owned() + WEBSITE),
+                r#type: ObjectiveError::UnknownCombination,
+            }
+        }
+
         pub(crate) fn get_target_error(s: &str) -> ParseObjectivesError {
             const WEBSITE: &str = " please visit http://xcsp.org/specifications/objectives/";
             ParseObjectivesError {
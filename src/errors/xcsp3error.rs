@@ -39,8 +39,10 @@
  */
 
 pub mod xcsp3_core {
+    use crate::errors::parse_annotations_error::xcsp3_core::ParseAnnotationsError;
     use crate::errors::parse_constraint_error::xcsp3_core::ParseConstraintError;
     use crate::errors::parse_domain_error::xcsp3_core::ParseDomainError;
+    use crate::errors::parse_framework_error::xcsp3_core::ParseFrameworkError;
     use crate::errors::parse_objectives_error::xcsp3_core::ParseObjectivesError;
     use crate::errors::parse_variable_error::ParseVariableError;
     use quick_xml::DeError;
@@ -52,6 +54,8 @@ pub mod xcsp3_core {
         ParseVariableError(ParseVariableError),
         ParseConstraintError(ParseConstraintError),
         ParseObjectivesError(ParseObjectivesError),
+        ParseAnnotationsError(ParseAnnotationsError),
+        ParseFrameworkError(ParseFrameworkError),
         ReadXmlError(DeError),
     }
 
@@ -65,6 +69,8 @@ pub mod xcsp3_core {
                     Xcsp3Error::ParseVariableError(e) => e.to_string(),
                     Xcsp3Error::ParseConstraintError(e) => e.to_string(),
                     Xcsp3Error::ParseObjectivesError(e) => e.to_string(),
+                    Xcsp3Error::ParseAnnotationsError(e) => e.to_string(),
+                    Xcsp3Error::ParseFrameworkError(e) => e.to_string(),
                     Xcsp3Error::ReadXmlError(e) => e.to_string(),
                 }
             )
@@ -91,10 +97,30 @@ pub mod xcsp3_core {
             Xcsp3Error::ParseObjectivesError(ParseObjectivesError::get_type_error(s))
         }
 
+        pub fn get_objective_combination_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseObjectivesError(ParseObjectivesError::get_combination_error(s))
+        }
+
         pub fn get_objective_target_error(s: &str) -> Xcsp3Error {
             Xcsp3Error::ParseObjectivesError(ParseObjectivesError::get_target_error(s))
         }
 
+        pub fn get_annotation_scope_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseAnnotationsError(ParseAnnotationsError::get_scope_error(s))
+        }
+
+        pub fn get_annotation_heuristic_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseAnnotationsError(ParseAnnotationsError::get_heuristic_error(s))
+        }
+
+        pub fn get_framework_scope_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseFrameworkError(ParseFrameworkError::get_scope_error(s))
+        }
+
+        pub fn get_framework_bound_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseFrameworkError(ParseFrameworkError::get_bound_error(s))
+        }
+
         pub fn get_constraint_element_error(s: &str) -> Xcsp3Error {
             Xcsp3Error::ParseConstraintError(ParseConstraintError::get_element_error(s))
         }
@@ -126,6 +152,50 @@ pub mod xcsp3_core {
         pub fn get_constraint_channel_error(s: &str) -> Xcsp3Error {
             Xcsp3Error::ParseConstraintError(ParseConstraintError::get_channel_error(s))
         }
+        pub fn get_constraint_circuit_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_circuit_error(s))
+        }
+        pub fn get_constraint_lex_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_lex_error(s))
+        }
+        pub fn get_constraint_precedence_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_precedence_error(s))
+        }
+        pub fn get_constraint_bin_packing_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_bin_packing_error(s))
+        }
+        pub fn get_constraint_clause_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_clause_error(s))
+        }
+        pub fn get_constraint_all_distant_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_all_distant_error(s))
+        }
+        pub fn get_constraint_balance_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_balance_error(s))
+        }
+        pub fn get_constraint_spread_deviation_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_spread_deviation_error(s))
+        }
+        pub fn get_constraint_knapsack_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_knapsack_error(s))
+        }
+        pub fn get_constraint_flow_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_flow_error(s))
+        }
+        pub fn get_constraint_meta_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_meta_error(s))
+        }
+        pub fn get_constraint_evaluation_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_evaluation_error(s))
+        }
+
+        pub fn get_constraint_linear_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_linear_error(s))
+        }
+
+        pub fn get_constraint_soft_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseConstraintError(ParseConstraintError::get_soft_error(s))
+        }
         pub fn get_constraint_extension_error(s: &str) -> Xcsp3Error {
             Xcsp3Error::ParseConstraintError(ParseConstraintError::get_extension_error(s))
         }
@@ -145,6 +215,14 @@ pub mod xcsp3_core {
             Xcsp3Error::ParseDomainError(ParseDomainError::get_integer_error(s))
         }
 
+        pub fn get_domain_real_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseDomainError(ParseDomainError::get_real_error(s))
+        }
+
+        pub fn get_domain_symbolic_error(s: &str) -> Xcsp3Error {
+            Xcsp3Error::ParseDomainError(ParseDomainError::get_symbolic_error(s))
+        }
+
         pub fn get_domain_interval_error(s: &str) -> Xcsp3Error {
             Xcsp3Error::ParseDomainError(ParseDomainError::get_interval_error(s))
         }
@@ -0,0 +1,94 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 10:31
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use std::fmt::{Display, Formatter};
+    use std::str::FromStr;
+
+    /// the lower and upper bounds of the costs of WCSP instances, given by the attributes lb and ub of instance
+    #[derive(Clone, Debug, Default)]
+    pub struct XCostBounds {
+        lb: Option<i64>,
+        ub: Option<i64>,
+    }
+
+    impl Display for XCostBounds {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "XCostBounds: lb = {:?} ub = {:?}", self.lb, self.ub)
+        }
+    }
+
+    impl XCostBounds {
+        pub fn from_str(lb: &str, ub: &str) -> Result<Self, Xcsp3Error> {
+            Ok(Self::new(
+                XCostBounds::parse_bound(lb)?,
+                XCostBounds::parse_bound(ub)?,
+            ))
+        }
+
+        pub fn new(lb: Option<i64>, ub: Option<i64>) -> Self {
+            Self { lb, ub }
+        }
+
+        fn parse_bound(bound: &str) -> Result<Option<i64>, Xcsp3Error> {
+            let bound = bound.trim();
+            if bound.is_empty() {
+                return Ok(None);
+            }
+            match i64::from_str(bound) {
+                Ok(n) => Ok(Some(n)),
+                Err(_) => Err(Xcsp3Error::get_framework_bound_error(&format!(
+                    "parse the cost bound {} error, ",
+                    bound
+                ))),
+            }
+        }
+
+        pub fn get_lb(&self) -> &Option<i64> {
+            &self.lb
+        }
+
+        /// the forbidden cost, a tuple whose cost reaches it is not allowed
+        pub fn get_ub(&self) -> &Option<i64> {
+            &self.ub
+        }
+    }
+}
@@ -0,0 +1,132 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 10:24
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum XQuantifierType {
+        Exists,
+        Forall,
+    }
+
+    /// a block of quantified variables, the variables are resolved into their ids
+    #[derive(Clone, Debug)]
+    pub struct XQuantifierBlock {
+        quantifier: XQuantifierType,
+        vars: Vec<String>,
+    }
+
+    impl XQuantifierBlock {
+        pub fn get_quantifier(&self) -> &XQuantifierType {
+            &self.quantifier
+        }
+
+        pub fn get_vars(&self) -> &Vec<String> {
+            &self.vars
+        }
+    }
+
+    /// the quantification of QCSP and QCOP instances, the blocks are kept in the declaration order
+    pub struct XQuantification<'a> {
+        set: &'a XVariableSet,
+        blocks: Vec<XQuantifierBlock>,
+    }
+
+    impl Display for XQuantification<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = "XQuantification:".to_string();
+            for b in self.blocks.iter() {
+                ret.push_str(&format!(" {:?}{:?}", b.quantifier, b.vars));
+            }
+            write!(f, "{}", ret)
+        }
+    }
+
+    impl<'a> XQuantification<'a> {
+        pub fn new(set: &'a XVariableSet) -> Self {
+            Self {
+                set,
+                blocks: vec![],
+            }
+        }
+
+        pub fn build_block(
+            &mut self,
+            quantifier: XQuantifierType,
+            list: &str,
+        ) -> Result<(), Xcsp3Error> {
+            let mut vars: Vec<String> = vec![];
+            for e in list.split_whitespace() {
+                match self.set.construct_scope(&[&e.to_string()]) {
+                    Ok(vec) => {
+                        for (id, _) in vec.into_iter() {
+                            vars.push(id);
+                        }
+                    }
+                    Err(_) => {
+                        return Err(Xcsp3Error::get_framework_scope_error(&format!(
+                            "the quantified variable {} is not found, ",
+                            e
+                        )));
+                    }
+                }
+            }
+            if vars.is_empty() {
+                return Err(Xcsp3Error::get_framework_scope_error(&format!(
+                    "the {:?} block has no variable, ",
+                    quantifier
+                )));
+            }
+            self.blocks.push(XQuantifierBlock { quantifier, vars });
+            Ok(())
+        }
+
+        pub fn get_blocks(&self) -> &Vec<XQuantifierBlock> {
+            &self.blocks
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.blocks.is_empty()
+        }
+    }
+}
@@ -25,9 +25,11 @@
 
 #![doc = include_str!("../README.md")]
 
+pub mod annotations;
 pub mod constraints;
 mod data_structs;
 mod errors;
+pub mod frameworks;
 pub mod objectives;
 pub mod utils;
 pub mod variables;
@@ -80,6 +80,11 @@ pub mod xcsp3_core {
             &self.expression
         }
 
+        /// return the interval [min, max] of the values of the objective
+        pub fn get_bounds(&self) -> Result<(i64, i64), Xcsp3Error> {
+            self.expression.bounds(self.set)
+        }
+
         pub fn get_scope(&mut self) -> Vec<(&String, &XDomainInteger)> {
             for e in &self.scope {
                 if let XVarVal::IntVar(s) = e {
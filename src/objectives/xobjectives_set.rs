@@ -40,18 +40,37 @@
 
 pub mod xcsp3_core {
 
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::objectives::xobjective_element::xcsp3_core::XObjectiveElement;
     use crate::objectives::xobjective_expression::xcsp3_core::XObjectiveExpression;
-    use crate::objectives::xobjectives_type::xcsp3_core::{XObjective, XObjectivesType};
+    use crate::objectives::xobjectives_type::xcsp3_core::{
+        XCombinationType, XObjective, XObjectivesType,
+    };
     use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
     use std::slice::{Iter, IterMut};
 
+    /// the objectives are kept in the order of declaration,
+    /// an invalid combination is kept as an error apart from the objectives
     pub struct XObjectivesSet<'a> {
         objectives: Vec<XObjectivesType<'a>>,
         set: &'a XVariableSet,
+        combination: Result<XCombinationType, Xcsp3Error>,
     }
 
     impl<'a> XObjectivesSet<'a> {
+        pub fn build_combination(&mut self, combination_str: &str) {
+            self.combination = match XCombinationType::get_combination_by_str(combination_str) {
+                Some(c) => Ok(c),
+                None => Err(Xcsp3Error::get_objective_combination_error(
+                    "the combination of objectives must be lexico or pareto, ",
+                )),
+            }
+        }
+
+        pub fn get_combination(&self) -> &Result<XCombinationType, Xcsp3Error> {
+            &self.combination
+        }
+
         pub fn build_maximize(
             &mut self,
             list: &str,
@@ -126,6 +145,7 @@ pub mod xcsp3_core {
             Self {
                 objectives: vec![],
                 set,
+                combination: Ok(XCombinationType::Pareto),
             }
         }
     }
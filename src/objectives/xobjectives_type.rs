@@ -44,6 +44,23 @@ pub mod xcsp3_core {
     use crate::objectives::xobjective_expression::xcsp3_core::XObjectiveExpression;
     use std::fmt::{Display, Formatter};
 
+    /// how several objectives are combined, pareto if the combination is not given
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum XCombinationType {
+        Lexico,
+        Pareto,
+    }
+
+    impl XCombinationType {
+        pub fn get_combination_by_str(s: &str) -> Option<Self> {
+            match s {
+                "lexico" => Some(XCombinationType::Lexico),
+                "" | "pareto" => Some(XCombinationType::Pareto),
+                _ => None,
+            }
+        }
+    }
+
     #[derive(Clone)]
     pub enum XObjectivesType<'a> {
         XObjectiveNone(Xcsp3Error),
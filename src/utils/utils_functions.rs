@@ -39,8 +39,13 @@
 pub mod xcsp3_utils {
     use crate::data_structs::xint_val_var::xcsp3_core::XVarVal;
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::variables::xdomain::xcsp3_core::{XBound, XDomainInteger};
+    use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
     // use std::str::FromStr;
 
+    /// a tuple of an extension, the star * is None
+    pub type XTuple = Vec<Option<i64>>;
+
     pub fn list_to_vec_var_val(list: &str) -> Result<Vec<XVarVal>, Xcsp3Error> {
         let mut ret: Vec<XVarVal> = vec![];
         let lists: Vec<&str> = list.split_whitespace().collect();
@@ -69,11 +74,13 @@ pub mod xcsp3_utils {
     //     ret
     // }
 
-    pub fn size_to_string(id: &str, size: &[usize]) -> String {
+    /// the name of a variable of an array, the start index is added to each index
+    /// eg x, [0,2], 1 -> x[1][3]
+    pub fn size_to_string(id: &str, size: &[usize], start_index: usize) -> String {
         let mut ret = id.to_string();
 
         for e in size.iter() {
-            ret.push_str(&format!("[{}]", e))
+            ret.push_str(&format!("[{}]", e + start_index))
         }
         ret
     }
@@ -137,8 +144,8 @@ pub mod xcsp3_utils {
 
     /// return the transitions,
     /// eg  "(a,0,a)(a,1,b)(b,1,c)(c,0,d)(d,0,d)(d,1,e)(e,0,e)" -> vec[ (a,0,a),(a,1,b),(b,1,c),(c,0,d),(d,0,d),(d,1,e),(e,0,e)]
-    pub fn list_to_transitions(list: &str) -> Result<Vec<(String, i32, String)>, Xcsp3Error> {
-        let mut ret: Vec<(String, i32, String)> = Vec::new();
+    pub fn list_to_transitions(list: &str) -> Result<Vec<(String, i64, String)>, Xcsp3Error> {
+        let mut ret: Vec<(String, i64, String)> = Vec::new();
         let chars = list.chars();
         if let Some(left) = list.find('(') {
             if let Some(right) = list.find(')') {
@@ -154,7 +161,7 @@ pub mod xcsp3_utils {
                 last = i;
             } else if x == ')' {
                 // println!("{}",&tuple_str[last+1..i]);
-                match list[last_comma1 + 1..last_comma2].parse::<i32>() {
+                match list[last_comma1 + 1..last_comma2].parse::<i64>() {
                     Ok(num) => ret.push((
                         list[last + 1..last_comma1].to_string(),
                         num,
@@ -197,13 +204,26 @@ pub mod xcsp3_utils {
         ret
     }
 
+    /// return the size of a list once its compact forms are expanded,
+    /// eg. "x[] y" with x of size 3 -> 4
+    pub fn list_size(list: &[XVarVal], set: &XVariableSet) -> Result<usize, Xcsp3Error> {
+        let mut size = 0;
+        for e in list.iter() {
+            match e {
+                XVarVal::IntVar(s) => size += set.construct_scope(&[s])?.len(),
+                _ => size += 1,
+            }
+        }
+        Ok(size)
+    }
+
     /// return the list of values,
     /// eg str"1 3 5 76" -> vec[1,3,5,76],
-    pub fn list_to_values(list: &str) -> Result<Vec<i32>, Xcsp3Error> {
-        let mut ret: Vec<i32> = Vec::new();
+    pub fn list_to_values(list: &str) -> Result<Vec<i64>, Xcsp3Error> {
+        let mut ret: Vec<i64> = Vec::new();
         let lists: Vec<&str> = list.split_whitespace().collect();
         for l in lists.iter() {
-            match l.parse::<i32>() {
+            match l.parse::<i64>() {
                 Ok(n) => ret.push(n),
                 Err(_) => {
                     return Err(Xcsp3Error::get_constraint_list_of_values_error(
@@ -215,6 +235,101 @@ pub mod xcsp3_utils {
         Ok(ret)
     }
 
+    /// return the values of a list of symbols, the i-th symbol is mapped by the i-th domain,
+    /// eg. red green with the domains {red green blue} -> [0, 1]
+    pub fn symbolic_list_to_values(
+        list: &str,
+        domains: &[&XDomainInteger],
+    ) -> Result<Vec<i64>, Xcsp3Error> {
+        let symbols: Vec<&str> = list.split_whitespace().collect();
+        if symbols.len() != domains.len() {
+            return Err(Xcsp3Error::get_constraint_list_of_values_error(
+                "the number of symbols is not equal to the number of variables. ",
+            ));
+        }
+        symbols
+            .iter()
+            .zip(domains.iter())
+            .map(|(s, d)| symbolic_to_value(s, d))
+            .collect()
+    }
+
+    /// return the tuples of symbols, the i-th symbol of a tuple is mapped by the i-th domain, the star * is None,
+    /// eg. (red,green)(blue,*) with the domains {red green blue} -> [[0,1],[2,*]]
+    pub fn symbolic_tuple_to_vector(
+        tuple_str: &str,
+        domains: &[&XDomainInteger],
+    ) -> Result<Vec<XTuple>, Xcsp3Error> {
+        let mut ret: Vec<XTuple> = Vec::new();
+        if domains.is_empty() {
+            return Err(Xcsp3Error::get_constraint_extension_error(
+                "parse the tuple of extension error",
+            ));
+        }
+        if !tuple_str.contains('(') {
+            for s in tuple_str.split_whitespace() {
+                ret.push(vec![symbolic_to_star_or_value(s, domains[0])?]);
+            }
+            return Ok(ret);
+        }
+        for tuple in tuple_str.split(')') {
+            let tuple = tuple.trim();
+            if tuple.is_empty() {
+                continue;
+            }
+            let symbols: Vec<&str> = tuple.trim_start_matches('(').split(',').collect();
+            if symbols.len() != domains.len() {
+                return Err(Xcsp3Error::get_constraint_extension_error(
+                    "parse the tuple of extension error",
+                ));
+            }
+            let mut tt: XTuple = Vec::with_capacity(symbols.len());
+            for (s, d) in symbols.iter().zip(domains.iter()) {
+                tt.push(symbolic_to_star_or_value(s.trim(), d)?);
+            }
+            ret.push(tt);
+        }
+        Ok(ret)
+    }
+
+    /// map a symbol of a tuple to its value in the domain, the star * is None
+    fn symbolic_to_star_or_value(
+        symbol: &str,
+        domain: &XDomainInteger,
+    ) -> Result<Option<i64>, Xcsp3Error> {
+        if symbol == "*" {
+            Ok(None)
+        } else {
+            symbolic_to_value(symbol, domain).map(Some)
+        }
+    }
+
+    /// map a symbol to its value in the domain
+    fn symbolic_to_value(symbol: &str, domain: &XDomainInteger) -> Result<i64, Xcsp3Error> {
+        if !domain.contain_symbol() {
+            return symbol.parse::<i64>().map_err(|_| {
+                Xcsp3Error::get_domain_symbolic_error(
+                    &("the domain is not symbolic, can not map ".to_owned() + symbol),
+                )
+            });
+        }
+        match domain.find_symbolic(symbol) {
+            usize::MAX => Err(Xcsp3Error::get_domain_symbolic_error(
+                &("can not find the symbol ".to_owned() + symbol),
+            )),
+            n => Ok(n as i64),
+        }
+    }
+
+    /// parse a bound of an interval, eg. -infinity, 5 or +infinity
+    pub fn str_to_bound(s: &str) -> Option<XBound> {
+        match s {
+            "+infinity" | "infinity" => Some(XBound::PositiveInfinity),
+            "-infinity" => Some(XBound::NegativeInfinity),
+            _ => s.parse::<i64>().ok().map(XBound::Value),
+        }
+    }
+
     /// return the list of values,
     /// eg str"(1, 3, 5, 76)" -> vec[1,3,5,76],
     pub fn list_with_bracket_comma_to_values(list: &str) -> Result<Vec<XVarVal>, Xcsp3Error> {
@@ -309,11 +424,11 @@ pub mod xcsp3_utils {
     //     }
     //     // println!("parse Extension {:?}",ti.get());
     // }
-    ///return the tuples by given string,
-    /// eg (0,0,1)(0,1,0)(1,0,0)(1,1,1) -> [[0,0,1],[0,1,0],[1,0,0],[1,1,1]]
-    pub fn tuple_to_vector(tuple_str: &str, is_unary: bool) -> Result<Vec<Vec<i32>>, Xcsp3Error> {
+    ///return the tuples by given string, the star * is None,
+    /// eg (0,0,1)(0,1,*) -> [[Some(0),Some(0),Some(1)],[Some(0),Some(1),None]]
+    pub fn tuple_to_vector(tuple_str: &str, is_unary: bool) -> Result<Vec<XTuple>, Xcsp3Error> {
         // let ti = TimeInterval::new();
-        let mut ret: Vec<Vec<i32>> = Vec::new();
+        let mut ret: Vec<XTuple> = Vec::new();
         let err = Xcsp3Error::get_constraint_extension_error("parse the tuple of extension error");
         if is_unary {
             let tuples: Vec<&str> = tuple_str.split_whitespace().collect();
@@ -321,14 +436,14 @@ pub mod xcsp3_utils {
                 if tuple.contains("..") {
                     let interval: Vec<&str> = tuple.split("..").collect();
                     if interval.len() == 2 {
-                        let left = interval[0].parse::<i32>();
-                        let right = interval[1].parse::<i32>();
+                        let left = interval[0].parse::<i64>();
+                        let right = interval[1].parse::<i64>();
                         match left {
                             Ok(l) => match right {
                                 Ok(r) => {
                                     if l <= r {
                                         for i in l..r + 1 {
-                                            ret.push(vec![i])
+                                            ret.push(vec![Some(i)])
                                         }
                                     } else {
                                         return Err(err);
@@ -344,8 +459,8 @@ pub mod xcsp3_utils {
                         }
                     }
                 } else {
-                    match tuple.parse::<i32>() {
-                        Ok(v) => ret.push(vec![v]),
+                    match tuple.parse::<i64>() {
+                        Ok(v) => ret.push(vec![Some(v)]),
                         Err(_) => {
                             return Err(err);
                         }
@@ -355,7 +470,7 @@ pub mod xcsp3_utils {
         } else {
             let chars = tuple_str.chars();
             let mut last = 0;
-            let mut tt: Vec<i32> = vec![];
+            let mut tt: XTuple = vec![];
             let mut n: usize = 0;
             if let Some(left) = tuple_str.find('(') {
                 if let Some(right) = tuple_str.find(')') {
@@ -370,13 +485,13 @@ pub mod xcsp3_utils {
                     last = i;
                 } else if x == ')' {
                     // println!("{}",&tuple_str[last+1..i]);
-                    match tuple_str[last + 1..i].parse::<i32>() {
+                    match tuple_str[last + 1..i].parse::<i64>() {
                         Ok(num) => {
-                            tt.push(num);
+                            tt.push(Some(num));
                         }
                         Err(_) => {
                             if &tuple_str[last + 1..i] == "*" {
-                                tt.push(i32::MAX);
+                                tt.push(None);
                             } else {
                                 return Err(err);
                             }
@@ -385,13 +500,13 @@ pub mod xcsp3_utils {
                     ret.push(tt.clone())
                 } else if x == ',' {
                     // println!("{}",&tuple_str[last+1..i]);
-                    match tuple_str[last + 1..i].parse::<i32>() {
+                    match tuple_str[last + 1..i].parse::<i64>() {
                         Ok(num) => {
-                            tt.push(num);
+                            tt.push(Some(num));
                         }
                         Err(_) => {
                             if &tuple_str[last + 1..i] == "*" {
-                                tt.push(i32::MAX);
+                                tt.push(None);
                             } else {
                                 return Err(err);
                             }
@@ -405,11 +520,56 @@ pub mod xcsp3_utils {
         Ok(ret)
     }
 
-    /// transform the string size to vector sizes
-    /// eg:  [2][3..4][4..8] -> ([2,3,4],[2,4,8])
-    pub fn sizes_to_double_vec(sizes: &str) -> Result<(Vec<usize>, Vec<usize>), Xcsp3Error> {
+    /// transform the tuples of a soft extension to the tuples and their costs
+    /// eg: (0,1):5 (1,*):3 -> ([[0,1],[1,*]],[5,3]), and 1..2:4 -> ([[1],[2]],[4,4])
+    pub fn tuple_with_costs_to_vector(
+        tuple_str: &str,
+    ) -> Result<(Vec<XTuple>, Vec<i64>), Xcsp3Error> {
+        let mut tuples: Vec<XTuple> = vec![];
+        let mut costs: Vec<i64> = vec![];
+        let tuple_str = tuple_str
+            .split(':')
+            .map(|s| s.trim())
+            .collect::<Vec<&str>>()
+            .join(":");
+        for e in tuple_str.split_whitespace() {
+            let err = Xcsp3Error::get_constraint_soft_error(&format!(
+                "parse the tuple {} of soft extension error, ",
+                e
+            ));
+            match e.rsplit_once(':') {
+                Some((tuple, cost)) => match cost.parse::<i64>() {
+                    Ok(c) => {
+                        let vec = tuple_to_vector(tuple, !tuple.contains('('))?;
+                        costs.extend(vec.iter().map(|_| c));
+                        tuples.extend(vec);
+                    }
+                    Err(_) => return Err(err),
+                },
+                None => return Err(err),
+            }
+        }
+        Ok((tuples, costs))
+    }
+
+    /// transform the string size to vector sizes, the indices are shifted by the start index of the array
+    /// eg:  [2][3..4][4..8] -> ([2,3,4],[2,4,8]), and with the start index 1 -> ([1,2,3],[1,3,7])
+    pub fn sizes_to_double_vec(
+        sizes: &str,
+        start_index: usize,
+    ) -> Result<(Vec<usize>, Vec<usize>), Xcsp3Error> {
         let mut lower: Vec<usize> = vec![];
         let mut upper: Vec<usize> = vec![];
+        let shift = |n: &str| match n.parse::<usize>() {
+            Ok(v) if v >= start_index => Ok(v - start_index),
+            Ok(_) => Err(Xcsp3Error::get_variable_size_invalid_error(&format!(
+                "the index {} is less than the start index {}, ",
+                n, start_index
+            ))),
+            Err(_) => Err(Xcsp3Error::get_domain_for_error(
+                "parse the domain for error",
+            )),
+        };
         let sizes = sizes.replace("[]", "[*]").replace(['[', ']'], " ");
         let nums: Vec<&str> = sizes.split_whitespace().collect();
         for n in nums.iter() {
@@ -419,38 +579,13 @@ pub mod xcsp3_utils {
             } else if n.contains("..") {
                 let interval: Vec<&str> = n.split("..").collect();
                 if interval.len() == 2 {
-                    let low = interval[0].parse::<usize>();
-                    let up = interval[1].parse::<usize>();
-
-                    match low {
-                        Ok(l) => match up {
-                            Ok(u) => {
-                                lower.push(l);
-                                upper.push(u);
-                            }
-                            Err(_) => {
-                                return Err(Xcsp3Error::get_domain_for_error(
-                                    "parse the domain for error",
-                                ));
-                            }
-                        },
-                        Err(_) => {
-                            return Err(Xcsp3Error::get_domain_for_error(
-                                "parse the domain for error",
-                            ));
-                        }
-                    }
+                    lower.push(shift(interval[0])?);
+                    upper.push(shift(interval[1])?);
                 }
             } else {
-                match n.parse::<usize>() {
-                    Ok(v) => {
-                        lower.push(v);
-                        upper.push(v);
-                    }
-                    Err(_) => {
-                        return Err(Xcsp3Error::get_domain_for_error("parse the domain error"));
-                    }
-                };
+                let v = shift(n)?;
+                lower.push(v);
+                upper.push(v);
             }
         }
         Ok((lower, upper))
@@ -41,7 +41,14 @@ pub mod xvariable_set;
 pub mod xvariable_type;
 
 pub mod xdomain;
+pub mod xdomain_bitset;
+pub mod xdomain_real;
+pub mod xdomain_set;
 mod xvariable_array;
 mod xvariable_int;
+mod xvariable_real;
+mod xvariable_real_array;
+mod xvariable_set_array;
+mod xvariable_set_var;
 mod xvariable_trait;
 mod xvariable_tree;
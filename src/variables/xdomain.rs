@@ -39,6 +39,8 @@
 
 pub mod xcsp3_core {
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::str_to_bound;
+    use crate::variables::xdomain_bitset::xcsp3_core::XDomainBitset;
     use std::fmt::{Display, Formatter};
     use std::str::FromStr;
 
@@ -78,7 +80,7 @@ pub mod xcsp3_core {
                     _ => false,
                 },
                 XIntegerType::XIntegerSymbolic(ii) => match arg {
-                    XIntegerType::XIntegerSymbolic(iii) => ii.equals(iii),
+                    XIntegerType::XIntegerSymbolic(iii) => ii.symbolic == iii.symbolic,
                     _ => false,
                 },
                 XIntegerType::XIntegerNone => false,
@@ -95,22 +97,23 @@ pub mod xcsp3_core {
         //         }
         //     }
         // }
-        pub fn maximum(&self) -> i32 {
+        /// the greatest value, None for XIntegerNone
+        pub fn maximum(&self) -> Option<i64> {
             match self {
-                XIntegerType::IntegerValue(iv) => iv.maximum(),
-                XIntegerType::IntegerInterval(ii) => ii.maximum(),
-                XIntegerType::XIntegerSymbolic(ii) => ii.maximum(),
-                XIntegerType::XIntegerNone => i32::MAX,
-                //i32::MAX, my ide named clion tell me i32::MAX is private constant, but rustc can compile it...
+                XIntegerType::IntegerValue(iv) => Some(iv.maximum()),
+                XIntegerType::IntegerInterval(ii) => Some(ii.maximum()),
+                XIntegerType::XIntegerSymbolic(ii) => Some(ii.maximum()),
+                XIntegerType::XIntegerNone => None,
             }
         }
 
-        pub fn minimum(&self) -> i32 {
+        /// the smallest value, None for XIntegerNone
+        pub fn minimum(&self) -> Option<i64> {
             match self {
-                XIntegerType::IntegerValue(iv) => iv.minimum(),
-                XIntegerType::IntegerInterval(ii) => ii.minimum(),
-                XIntegerType::XIntegerSymbolic(ii) => ii.minimum(),
-                XIntegerType::XIntegerNone => -2_147_483_648i32, //i32::MIN,
+                XIntegerType::IntegerValue(iv) => Some(iv.minimum()),
+                XIntegerType::IntegerInterval(ii) => Some(ii.minimum()),
+                XIntegerType::XIntegerSymbolic(ii) => Some(ii.minimum()),
+                XIntegerType::XIntegerNone => None,
             }
         }
     }
@@ -118,20 +121,20 @@ pub mod xcsp3_core {
     pub trait XIntegerEntity: Display {
         fn width(&self) -> usize;
 
-        fn minimum(&self) -> i32;
+        fn minimum(&self) -> i64;
 
-        fn maximum(&self) -> i32;
+        fn maximum(&self) -> i64;
 
         fn equals(&self, arg: &dyn XIntegerEntity) -> bool;
     }
 
     #[derive(Copy, Clone)]
     pub struct XIntegerValue {
-        value: i32,
+        value: i64,
     }
 
-    impl From<i32> for XIntegerValue {
-        fn from(value: i32) -> Self {
+    impl From<i64> for XIntegerValue {
+        fn from(value: i64) -> Self {
             XIntegerValue { value }
         }
     }
@@ -147,11 +150,11 @@ pub mod xcsp3_core {
             1
         }
 
-        fn minimum(&self) -> i32 {
+        fn minimum(&self) -> i64 {
             self.value
         }
 
-        fn maximum(&self) -> i32 {
+        fn maximum(&self) -> i64 {
             self.value
         }
 
@@ -162,7 +165,7 @@ pub mod xcsp3_core {
 
     #[derive(Clone)]
     pub struct XIntegerSymbolic {
-        values: Vec<i32>,
+        values: Vec<i64>,
         symbolic: Vec<String>,
     }
 
@@ -170,9 +173,9 @@ pub mod xcsp3_core {
         pub fn new(domain: &str) -> XIntegerSymbolic {
             let dds: Vec<&str> = domain.split_whitespace().collect();
             let mut symbolic: Vec<String> = vec![];
-            let mut values: Vec<i32> = vec![];
+            let mut values: Vec<i64> = vec![];
             for (i, s) in dds.iter().enumerate() {
-                values.push(i as i32);
+                values.push(i as i64);
                 symbolic.push(s.to_string());
             }
             XIntegerSymbolic { symbolic, values }
@@ -186,6 +189,14 @@ pub mod xcsp3_core {
             }
             usize::MAX
         }
+
+        /// return the symbol of the given value, the reverse of find_symbolic
+        pub fn get_symbolic(&self, value: i64) -> Option<&str> {
+            self.values
+                .iter()
+                .position(|v| *v == value)
+                .map(|i| self.symbolic[i].as_str())
+        }
     }
 
     impl Display for XIntegerSymbolic {
@@ -203,11 +214,11 @@ pub mod xcsp3_core {
             self.values.len()
         }
 
-        fn minimum(&self) -> i32 {
+        fn minimum(&self) -> i64 {
             self.values[0]
         }
 
-        fn maximum(&self) -> i32 {
+        fn maximum(&self) -> i64 {
             self.values[self.values.len() - 1]
         }
 
@@ -216,46 +227,100 @@ pub mod xcsp3_core {
         }
     }
 
+    /// a bound of an integer interval, eg. -infinity..0 is [NegativeInfinity, Value(0)],
+    /// the bounds are ordered as NegativeInfinity < Value(_) < PositiveInfinity
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum XBound {
+        NegativeInfinity,
+        Value(i64),
+        PositiveInfinity,
+    }
+
+    impl Display for XBound {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            match self {
+                XBound::NegativeInfinity => write!(f, "-infinity"),
+                XBound::Value(v) => write!(f, "{}", v),
+                XBound::PositiveInfinity => write!(f, "+infinity"),
+            }
+        }
+    }
+
+    impl XBound {
+        /// the i64 value of the bound, the infinite bounds are clamped to the smallest and the greatest i64
+        pub fn clamped(&self) -> i64 {
+            match self {
+                XBound::NegativeInfinity => i64::MIN,
+                XBound::Value(v) => *v,
+                XBound::PositiveInfinity => i64::MAX,
+            }
+        }
+
+        pub fn is_infinite(&self) -> bool {
+            !matches!(self, XBound::Value(_))
+        }
+    }
+
     #[derive(Copy, Clone)]
     pub struct XIntegerInterval {
-        max: i32,
-        min: i32,
+        max: XBound,
+        min: XBound,
     }
 
     impl XIntegerInterval {
-        pub fn new(min: i32, max: i32) -> XIntegerInterval {
+        pub fn new(min: i64, max: i64) -> XIntegerInterval {
+            XIntegerInterval::from_bounds(XBound::Value(min), XBound::Value(max))
+        }
+
+        /// an interval whose bounds can be infinite, eg. 0..+infinity
+        pub fn from_bounds(min: XBound, max: XBound) -> XIntegerInterval {
             XIntegerInterval { max, min }
         }
+
+        pub fn get_min(&self) -> XBound {
+            self.min
+        }
+
+        pub fn get_max(&self) -> XBound {
+            self.max
+        }
     }
 
     impl Display for XIntegerInterval {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-            write!(f, "{}..{}", self.minimum(), self.maximum())
+            write!(f, "{}..{}", self.min, self.max)
         }
     }
 
     impl XIntegerEntity for XIntegerInterval {
+        /// the width of an infinite interval is usize::MAX
         fn width(&self) -> usize {
-            (self.max - self.min + 1) as usize
+            if self.min.is_infinite() || self.max.is_infinite() {
+                return usize::MAX;
+            }
+            usize::try_from(self.maximum() as i128 - self.minimum() as i128 + 1)
+                .unwrap_or(usize::MAX)
         }
 
-        fn minimum(&self) -> i32 {
-            self.min
+        /// the smallest i64 value, i64::MIN if the interval starts at -infinity
+        fn minimum(&self) -> i64 {
+            self.min.clamped()
         }
 
-        fn maximum(&self) -> i32 {
-            self.max
+        /// the greatest i64 value, i64::MAX if the interval ends at +infinity
+        fn maximum(&self) -> i64 {
+            self.max.clamped()
         }
 
         fn equals(&self, arg: &dyn XIntegerEntity) -> bool {
-            self.min == arg.minimum() && self.max == arg.maximum()
+            self.minimum() == arg.minimum() && self.maximum() == arg.maximum()
         }
     }
 
     #[derive(Clone)]
     pub struct XDomainInteger {
         size: usize,
-        top: i32,
+        top: i64,
         values: Vec<XIntegerType>,
     }
 
@@ -279,7 +344,7 @@ pub mod xcsp3_core {
         pub fn new() -> XDomainInteger {
             XDomainInteger {
                 size: 0,
-                top: i32::MIN,
+                top: i64::MIN,
                 values: vec![],
             }
         }
@@ -305,38 +370,69 @@ pub mod xcsp3_core {
             usize::MAX
         }
 
+        /// return the symbol of the given value, None if the domain is not symbolic
+        pub fn get_symbolic(&self, value: i64) -> Option<&str> {
+            for e in self.values.iter() {
+                if let XIntegerType::XIntegerSymbolic(symbol) = e {
+                    if let Some(s) = symbol.get_symbolic(value) {
+                        return Some(s);
+                    }
+                }
+            }
+            None
+        }
+
+        /// the symbols are mapped to the values 0, 1, 2, ...
         pub fn from_symbolic(domain: &str) -> XDomainInteger {
-            let entity = XIntegerType::XIntegerSymbolic(XIntegerSymbolic::new(domain));
+            let symbolic = XIntegerSymbolic::new(domain);
+            let size = symbolic.width();
             XDomainInteger {
-                size: 0,
-                top: 0,
-                values: vec![entity],
+                size,
+                top: size as i64 - 1,
+                values: vec![XIntegerType::XIntegerSymbolic(symbolic)],
             }
         }
 
+        /// parse the domain, the values and intervals can be unsorted or overlapping,
+        /// eg. 5 1..3 2 -> 1..3 5
         pub fn from_string(domain: &str) -> Result<XDomainInteger, Xcsp3Error> {
+            XDomainInteger::parse(domain, false)
+        }
+
+        /// parse the domain, return an error if the values and intervals are not strictly increasing
+        pub fn from_string_strict(domain: &str) -> Result<XDomainInteger, Xcsp3Error> {
+            XDomainInteger::parse(domain, true)
+        }
+
+        fn parse(domain: &str, strict: bool) -> Result<XDomainInteger, Xcsp3Error> {
             let mut ret: XDomainInteger = XDomainInteger::new();
+            // without strict, the entries are kept as parsed and normalized once at the end
+            let mut ranges: Vec<(XBound, XBound)> = vec![];
             let domains: Vec<&str> = domain.split_whitespace().collect();
 
             for d in domains.iter() {
                 if d.contains("..") {
                     let interval: Vec<&str> = d.split("..").collect();
                     if interval.len() == 2 {
-                        let left = i32::from_str(interval[0]);
-                        let right = i32::from_str(interval[1]);
+                        let left = str_to_bound(interval[0]);
+                        let right = str_to_bound(interval[1]);
                         match left {
-                            Ok(l) => match right {
-                                Ok(r) => {
-                                    ret.add_interval(l, r);
+                            Some(l) => match right {
+                                Some(r) => {
+                                    if strict {
+                                        ret.add_bounds_strict(l, r)?;
+                                    } else {
+                                        ranges.push((l, r));
+                                    }
                                 }
-                                Err(_) => {
+                                None => {
                                     ret.values.push(XIntegerType::XIntegerNone);
                                     return Err(Xcsp3Error::get_domain_interval_error(
                                         "parse the domain error",
                                     ));
                                 }
                             },
-                            Err(_) => {
+                            None => {
                                 ret.values.push(XIntegerType::XIntegerNone);
                                 return Err(Xcsp3Error::get_domain_interval_error(
                                     "parse the domain error",
@@ -345,8 +441,9 @@ pub mod xcsp3_core {
                         }
                     }
                 } else {
-                    match i32::from_str(d) {
-                        Ok(v) => ret.add_value(v),
+                    match i64::from_str(d) {
+                        Ok(v) if strict => ret.add_value_strict(v)?,
+                        Ok(v) => ranges.push((XBound::Value(v), XBound::Value(v))),
                         Err(_) => {
                             ret.values.push(XIntegerType::XIntegerNone);
                             return Err(Xcsp3Error::get_domain_integer_error(
@@ -356,17 +453,19 @@ pub mod xcsp3_core {
                     };
                 }
             }
-
+            if !strict {
+                ret = XDomainInteger::from_ranges(ranges);
+            }
             Ok(ret)
         }
 
         fn add_entity(&mut self, entity: XIntegerType) {
             match entity {
                 XIntegerType::IntegerValue(iv) => {
-                    self.size += iv.width();
+                    self.size = self.size.saturating_add(iv.width());
                 }
                 XIntegerType::IntegerInterval(ii) => {
-                    self.size += ii.width();
+                    self.size = self.size.saturating_add(ii.width());
                 }
                 _ => {}
             }
@@ -374,10 +473,15 @@ pub mod xcsp3_core {
             self.values.push(entity);
         }
 
+        /// return true if the two domains have the same values, whatever the order of their entries,
+        /// the symbolic domains must have the same symbols
         pub fn equals(&self, arg: &XDomainInteger) -> bool {
             if self.nb_values() != arg.nb_values() {
                 return false;
             }
+            if !self.contain_symbol() && !arg.contain_symbol() {
+                return merge_ranges(self.ranges()) == merge_ranges(arg.ranges());
+            }
             if self.values.len() != arg.values.len() {
                 return false;
             }
@@ -389,112 +493,345 @@ pub mod xcsp3_core {
             true
         }
 
-        pub fn add_value(&mut self, value: i32) {
-            if value > self.top {
-                let b = XIntegerType::IntegerValue(XIntegerValue::from(value));
+        /// add a value, the domain is normalized if the value is not greater than the maximum,
+        /// return an error if the domain has to be normalized and is symbolic
+        pub fn add_value(&mut self, value: i64) -> Result<(), Xcsp3Error> {
+            let b = XIntegerType::IntegerValue(XIntegerValue::from(value));
+            if self.values.is_empty() || value > self.top {
                 self.add_entity(b);
                 self.top = value;
+                Ok(())
             } else {
-                panic!("not sequence domain");
+                self.normalize_with(b)
             }
         }
 
-        pub fn add_interval(&mut self, min: i32, max: i32) {
-            if min > self.top && min <= max {
-                let b = XIntegerType::IntegerInterval(XIntegerInterval::new(min, max));
+        /// add an interval, the domain is normalized if the interval is not after the maximum,
+        /// an empty interval (min > max) is ignored
+        pub fn add_interval(&mut self, min: i64, max: i64) -> Result<(), Xcsp3Error> {
+            self.add_bounds(XBound::Value(min), XBound::Value(max))
+        }
+
+        /// add an interval whose bounds can be infinite, like add_interval
+        pub fn add_bounds(&mut self, min: XBound, max: XBound) -> Result<(), Xcsp3Error> {
+            if min > max {
+                return Ok(());
+            }
+            let b = XIntegerType::IntegerInterval(XIntegerInterval::from_bounds(min, max));
+            if self.values.is_empty() || min > XBound::Value(self.top) {
                 self.add_entity(b);
-                self.top = max;
+                self.top = max.clamped();
+                Ok(())
+            } else {
+                self.normalize_with(b)
+            }
+        }
+
+        /// add a value, return an error if the value is not greater than the maximum
+        pub fn add_value_strict(&mut self, value: i64) -> Result<(), Xcsp3Error> {
+            if self.values.is_empty() || value > self.top {
+                self.add_value(value)
+            } else {
+                Err(Xcsp3Error::get_domain_integer_error(&format!(
+                    "the value {} is not greater than the previous values, ",
+                    value
+                )))
+            }
+        }
+
+        /// add an interval, return an error if the interval is empty or not after the maximum
+        pub fn add_interval_strict(&mut self, min: i64, max: i64) -> Result<(), Xcsp3Error> {
+            self.add_bounds_strict(XBound::Value(min), XBound::Value(max))
+        }
+
+        /// add an interval whose bounds can be infinite, like add_interval_strict
+        pub fn add_bounds_strict(&mut self, min: XBound, max: XBound) -> Result<(), Xcsp3Error> {
+            if (self.values.is_empty() || min > XBound::Value(self.top)) && min <= max {
+                self.add_bounds(min, max)
+            } else {
+                Err(Xcsp3Error::get_domain_interval_error(&format!(
+                    "the interval {}..{} is empty or not after the previous values, ",
+                    min, max
+                )))
+            }
+        }
+
+        /// add the entity, then sort the values and intervals, merge the overlapping or adjacent ones
+        /// and drop the duplicates, the symbols are not integers so a symbolic domain is an error
+        fn normalize_with(&mut self, entity: XIntegerType) -> Result<(), Xcsp3Error> {
+            if self.contain_symbol() {
+                return Err(Xcsp3Error::get_domain_symbolic_error(
+                    "a symbolic domain can not be normalized, ",
+                ));
+            }
+            self.values.push(entity);
+            *self = XDomainInteger::from_ranges(self.ranges());
+            Ok(())
+        }
+
+        /// the intervals [min, max] of the integer values of the domain, the symbols are skipped
+        fn ranges(&self) -> Vec<(XBound, XBound)> {
+            self.values
+                .iter()
+                .filter_map(|e| match e {
+                    XIntegerType::IntegerValue(v) => {
+                        Some((XBound::Value(v.value), XBound::Value(v.value)))
+                    }
+                    XIntegerType::IntegerInterval(i) => Some((i.min, i.max)),
+                    XIntegerType::XIntegerSymbolic(_) | XIntegerType::XIntegerNone => None,
+                })
+                .collect()
+        }
+
+        /// build a normalized domain from the intervals [min, max], the empty ones are ignored
+        fn from_ranges(ranges: Vec<(XBound, XBound)>) -> XDomainInteger {
+            let mut ret = XDomainInteger::new();
+            for (min, max) in merge_ranges(ranges).into_iter() {
+                match (min, max) {
+                    (XBound::Value(v), XBound::Value(w)) if v == w => {
+                        ret.add_entity(XIntegerType::IntegerValue(XIntegerValue::from(v)))
+                    }
+                    _ => ret.add_entity(XIntegerType::IntegerInterval(
+                        XIntegerInterval::from_bounds(min, max),
+                    )),
+                }
+                ret.top = max.clamped();
+            }
+            ret
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.size == 0
+        }
+
+        /// return true if the value is in the domain
+        pub fn contains(&self, value: i64) -> bool {
+            let i = self
+                .values
+                .partition_point(|e| e.maximum().is_some_and(|m| m < value));
+            i < self.values.len() && self.values[i].minimum().is_some_and(|m| m <= value)
+        }
+
+        /// the set operations build integer domains, so the symbols would be lost
+        fn check_not_symbolic(&self) -> Result<(), Xcsp3Error> {
+            if self.contain_symbol() {
+                Err(Xcsp3Error::get_domain_symbolic_error(
+                    "the set operations are not supported on symbolic domains, ",
+                ))
             } else {
-                panic!("not sequence domain");
+                Ok(())
             }
         }
 
+        /// return the intersection of the two domains, an error if one of them is symbolic
+        pub fn intersect(&self, other: &XDomainInteger) -> Result<XDomainInteger, Xcsp3Error> {
+            self.check_not_symbolic()?;
+            other.check_not_symbolic()?;
+            let (a, b) = (self.ranges(), other.ranges());
+            let mut ranges: Vec<(XBound, XBound)> = vec![];
+            let (mut i, mut j) = (0, 0);
+            while i < a.len() && j < b.len() {
+                let min = a[i].0.max(b[j].0);
+                let max = a[i].1.min(b[j].1);
+                if min <= max {
+                    ranges.push((min, max));
+                }
+                if a[i].1 < b[j].1 {
+                    i += 1;
+                } else {
+                    j += 1;
+                }
+            }
+            Ok(XDomainInteger::from_ranges(ranges))
+        }
+
+        /// return the union of the two domains, an error if one of them is symbolic
+        pub fn union(&self, other: &XDomainInteger) -> Result<XDomainInteger, Xcsp3Error> {
+            self.check_not_symbolic()?;
+            other.check_not_symbolic()?;
+            let mut ranges = self.ranges();
+            ranges.extend(other.ranges());
+            Ok(XDomainInteger::from_ranges(ranges))
+        }
+
+        pub fn remove_value(&mut self, value: i64) -> Result<(), Xcsp3Error> {
+            self.remove_interval(value, value)
+        }
+
+        /// remove the values between min and max (both included), an error if the domain is symbolic
+        pub fn remove_interval(&mut self, min: i64, max: i64) -> Result<(), Xcsp3Error> {
+            self.check_not_symbolic()?;
+            if min > max || !self.intersects(min, max) {
+                return Ok(());
+            }
+            let mut ranges: Vec<(XBound, XBound)> = vec![];
+            for (l, r) in self.ranges().into_iter() {
+                if r < XBound::Value(min) || l > XBound::Value(max) {
+                    ranges.push((l, r));
+                } else {
+                    // there is no i64 value before i64::MIN or after i64::MAX
+                    if let (true, Some(m)) = (l < XBound::Value(min), min.checked_sub(1)) {
+                        ranges.push((l, XBound::Value(m)));
+                    }
+                    if let (true, Some(m)) = (r > XBound::Value(max), max.checked_add(1)) {
+                        ranges.push((XBound::Value(m), r));
+                    }
+                }
+            }
+            *self = XDomainInteger::from_ranges(ranges);
+            Ok(())
+        }
+
+        /// remove the values which are less than min or greater than max, an error if the domain is symbolic
+        pub fn restrict_to_bounds(&mut self, min: i64, max: i64) -> Result<(), Xcsp3Error> {
+            self.check_not_symbolic()?;
+            let ranges = self
+                .ranges()
+                .into_iter()
+                .map(|(l, r)| (l.max(XBound::Value(min)), r.min(XBound::Value(max))))
+                .collect();
+            *self = XDomainInteger::from_ranges(ranges);
+            Ok(())
+        }
+
+        fn intersects(&self, min: i64, max: i64) -> bool {
+            let i = self
+                .values
+                .partition_point(|e| e.maximum().is_some_and(|m| m < min));
+            i < self.values.len() && self.values[i].minimum().is_some_and(|m| m <= max)
+        }
+
+        /// return the n-th value (from 0) of the domain
+        pub fn nth_value(&self, n: usize) -> Option<i64> {
+            let mut n = n;
+            for (min, max) in self.ranges().into_iter() {
+                let (min, max) = (min.clamped(), max.clamped());
+                let width = usize::try_from(max as i128 - min as i128 + 1).unwrap_or(usize::MAX);
+                if n < width {
+                    return Some(min + n as i64);
+                }
+                n -= width;
+            }
+            None
+        }
+
+        /// return the index of the value in the domain, the reverse of nth_value
+        pub fn index_of(&self, value: i64) -> Option<usize> {
+            let mut index: usize = 0;
+            for (min, max) in self.ranges().into_iter() {
+                let (min, max) = (min.clamped(), max.clamped());
+                if value < min {
+                    return None;
+                }
+                if value <= max {
+                    return usize::try_from(value as i128 - min as i128)
+                        .ok()
+                        .map(|i| index.saturating_add(i));
+                }
+                let width = usize::try_from(max as i128 - min as i128 + 1).unwrap_or(usize::MAX);
+                index = index.saturating_add(width);
+            }
+            None
+        }
+
+        /// return the dense bitset view of the domain, None if the domain is empty or wider than max_width
+        pub fn to_bitset(&self, max_width: usize) -> Option<XDomainBitset> {
+            let (min, max) = (self.minimum()?, self.maximum()?);
+            if max as i128 - min as i128 >= max_width as i128 {
+                return None;
+            }
+            let mut bitset = XDomainBitset::new(min, max).ok()?;
+            for (l, r) in self.ranges().into_iter() {
+                for v in l.clamped()..=r.clamped() {
+                    bitset.insert(v);
+                }
+            }
+            Some(bitset)
+        }
+
         pub fn nb_values(&self) -> usize {
             self.size
         }
 
-        pub fn minimum(&self) -> i32 {
-            self.values[0].minimum()
+        /// the smallest value, None if the domain is empty
+        pub fn minimum(&self) -> Option<i64> {
+            self.values.first().and_then(|e| e.minimum())
         }
 
-        pub fn maximum(&self) -> i32 {
-            self.values[self.values.len() - 1].maximum()
+        /// the greatest value, None if the domain is empty
+        pub fn maximum(&self) -> Option<i64> {
+            self.values.last().and_then(|e| e.maximum())
         }
 
         pub fn is_interval(&self) -> bool {
-            self.size == (self.maximum() - self.minimum() + 1) as usize
+            match (self.minimum(), self.maximum()) {
+                (Some(min), Some(max)) => {
+                    self.size
+                        == usize::try_from(max as i128 - min as i128 + 1).unwrap_or(usize::MAX)
+                }
+                _ => false,
+            }
         }
 
         pub fn iter(&self) -> XDomainIter {
             XDomainIter {
                 values: &self.values,
                 current: 0,
-                current1: i32::MAX, //i32::MAX {
+                current1: None,
+            }
+        }
+    }
+
+    /// sort the intervals [min, max], merge the overlapping or adjacent ones and drop the empty ones
+    fn merge_ranges(mut ranges: Vec<(XBound, XBound)>) -> Vec<(XBound, XBound)> {
+        ranges.retain(|(min, max)| {
+            min <= max && *min != XBound::PositiveInfinity && *max != XBound::NegativeInfinity
+        });
+        ranges.sort_unstable();
+        let mut merged: Vec<(XBound, XBound)> = Vec::with_capacity(ranges.len());
+        for (min, max) in ranges.into_iter() {
+            match merged.last_mut() {
+                Some(last) if min.clamped() as i128 <= last.1.clamped() as i128 + 1 => {
+                    last.1 = last.1.max(max)
+                }
+                _ => merged.push((min, max)),
             }
         }
+        merged
     }
 
     pub struct XDomainIter<'a> {
         values: &'a Vec<XIntegerType>,
         current: usize,
-        current1: i32,
+        /// the next value of the current interval, None if the interval is not started
+        current1: Option<i64>,
     }
 
     impl Iterator for XDomainIter<'_> {
-        type Item = i32;
+        type Item = i64;
 
         fn next(&mut self) -> Option<Self::Item> {
-            let mut ret: Option<Self::Item> = None;
-            if self.current >= self.values.len() {
-                return ret;
-            }
-            for cc in self.current..self.values.len() {
-                match &self.values[cc] {
-                    XIntegerType::XIntegerSymbolic(s) => {
-                        if self.current1 == i32::MAX {
-                            //i32::MAX {
-                            self.current1 = s.minimum();
-                            ret = Some(self.current1);
-                            self.current1 += 1;
-                            break;
-                        } else if self.current1 > s.maximum() {
-                            self.current += 1;
-                            continue;
-                        } else {
-                            ret = Some(self.current1);
-                            self.current1 += 1;
-                            break;
-                        }
-                    }
+            while self.current < self.values.len() {
+                let (min, max) = match &self.values[self.current] {
+                    XIntegerType::XIntegerSymbolic(s) => (s.minimum(), s.maximum()),
+                    XIntegerType::IntegerInterval(i) => (i.minimum(), i.maximum()),
+                    XIntegerType::IntegerValue(v) => (v.value, v.value),
                     XIntegerType::XIntegerNone => {
                         self.current += 1;
                         continue;
                     }
-                    XIntegerType::IntegerValue(v) => {
-                        self.current += 1;
-                        ret = Some(v.value);
-                        break;
-                    }
-                    XIntegerType::IntegerInterval(i) => {
-                        if self.current1 == i32::MAX {
-                            //i32::MAX {
-                            self.current1 = i.minimum();
-                            ret = Some(self.current1);
-                            self.current1 += 1;
-                            break;
-                        } else if self.current1 > i.maximum() {
-                            self.current1 = i32::MAX; //i32::MAX {
-                            self.current += 1;
-                            continue;
-                        } else {
-                            ret = Some(self.current1);
-                            self.current1 += 1;
-                            break;
-                        }
-                    }
+                };
+                let value = self.current1.unwrap_or(min);
+                // the bound can be infinite, so the next value is computed only if it exists
+                if value >= max {
+                    self.current1 = None;
+                    self.current += 1;
+                } else {
+                    self.current1 = Some(value + 1);
                 }
+                return Some(value);
             }
-            ret
+            None
         }
     }
 }
@@ -0,0 +1,146 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 10:40
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use std::fmt::{Display, Formatter};
+
+    /// the greatest number of values of a bitset, 2^24 values take 2 MB
+    pub const MAX_BITSET_WIDTH: usize = 1 << 24;
+
+    /// the dense view of a small integer domain, the bit i is set if the value min + i is in the domain
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct XDomainBitset {
+        min: i64,
+        max: i64,
+        words: Vec<u64>,
+    }
+
+    impl Display for XDomainBitset {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret = String::default();
+            for v in self.iter() {
+                ret.push_str(&v.to_string());
+                ret.push(' ');
+            }
+            write!(
+                f,
+                "XDomainBitset: min = {} max = {} values = {}",
+                self.min, self.max, ret
+            )
+        }
+    }
+
+    impl XDomainBitset {
+        /// an empty bitset for the values between min and max,
+        /// return an error if there are more than MAX_BITSET_WIDTH values
+        pub fn new(min: i64, max: i64) -> Result<XDomainBitset, Xcsp3Error> {
+            let width = usize::try_from((max as i128 - min as i128 + 1).max(0))
+                .ok()
+                .filter(|w| *w <= MAX_BITSET_WIDTH);
+            match width {
+                Some(width) => Ok(XDomainBitset {
+                    min,
+                    max,
+                    words: vec![0; (width + 63) / 64],
+                }),
+                None => Err(Xcsp3Error::get_domain_interval_error(&format!(
+                    "the bitset of {}..{} is too wide, ",
+                    min, max
+                ))),
+            }
+        }
+
+        fn position(&self, value: i64) -> Option<(usize, u64)> {
+            if value < self.min || value > self.max {
+                None
+            } else {
+                let i = (value - self.min) as usize;
+                Some((i / 64, 1 << (i % 64)))
+            }
+        }
+
+        /// insert the value, do nothing if the value is out of [min, max]
+        pub fn insert(&mut self, value: i64) {
+            if let Some((w, bit)) = self.position(value) {
+                self.words[w] |= bit;
+            }
+        }
+
+        pub fn remove(&mut self, value: i64) {
+            if let Some((w, bit)) = self.position(value) {
+                self.words[w] &= !bit;
+            }
+        }
+
+        pub fn contains(&self, value: i64) -> bool {
+            match self.position(value) {
+                Some((w, bit)) => self.words[w] & bit != 0,
+                None => false,
+            }
+        }
+
+        /// the number of values in the bitset
+        pub fn size(&self) -> usize {
+            self.words.iter().map(|w| w.count_ones() as usize).sum()
+        }
+
+        pub fn get_min(&self) -> i64 {
+            self.min
+        }
+
+        pub fn get_max(&self) -> i64 {
+            self.max
+        }
+
+        pub fn get_words(&self) -> &Vec<u64> {
+            &self.words
+        }
+
+        /// iterate the values in increasing order
+        pub fn iter(&self) -> impl Iterator<Item = i64> + '_ {
+            self.words.iter().enumerate().flat_map(move |(w, word)| {
+                (0..64)
+                    .filter(move |b| word & (1 << b) != 0)
+                    .map(move |b| self.min + (w * 64 + b) as i64)
+            })
+        }
+    }
+}
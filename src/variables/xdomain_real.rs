@@ -0,0 +1,208 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 13:05
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use std::fmt::{Display, Formatter};
+    use std::str::FromStr;
+
+    /// an interval of reals, eg. [1.5,3.25] or ]0,+infinity[
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct XRealInterval {
+        min: f64,
+        max: f64,
+        min_open: bool,
+        max_open: bool,
+    }
+
+    impl Display for XRealInterval {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "{}{},{}{}",
+                if self.min_open { ']' } else { '[' },
+                XRealInterval::bound_to_string(self.min),
+                XRealInterval::bound_to_string(self.max),
+                if self.max_open { '[' } else { ']' }
+            )
+        }
+    }
+
+    impl XRealInterval {
+        pub fn new(min: f64, max: f64, min_open: bool, max_open: bool) -> XRealInterval {
+            XRealInterval {
+                min,
+                max,
+                min_open,
+                max_open,
+            }
+        }
+
+        fn bound_to_string(bound: f64) -> String {
+            if bound == f64::INFINITY {
+                "+infinity".to_string()
+            } else if bound == f64::NEG_INFINITY {
+                "-infinity".to_string()
+            } else {
+                bound.to_string()
+            }
+        }
+
+        fn parse_bound(bound: &str) -> Option<f64> {
+            match bound.trim() {
+                "+infinity" | "infinity" => Some(f64::INFINITY),
+                "-infinity" => Some(f64::NEG_INFINITY),
+                b => match f64::from_str(b) {
+                    Ok(n) if n.is_finite() => Some(n),
+                    _ => None,
+                },
+            }
+        }
+
+        pub fn minimum(&self) -> f64 {
+            self.min
+        }
+
+        pub fn maximum(&self) -> f64 {
+            self.max
+        }
+
+        pub fn is_min_open(&self) -> bool {
+            self.min_open
+        }
+
+        pub fn is_max_open(&self) -> bool {
+            self.max_open
+        }
+
+        pub fn contains(&self, value: f64) -> bool {
+            (value > self.min || (!self.min_open && value == self.min))
+                && (value < self.max || (!self.max_open && value == self.max))
+        }
+    }
+
+    /// the domain of a real variable, which is a list of intervals
+    #[derive(Clone, Debug, Default)]
+    pub struct XDomainReal {
+        intervals: Vec<XRealInterval>,
+    }
+
+    impl Display for XDomainReal {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut s = String::new();
+            for e in self.intervals.iter() {
+                s = format!("{} {}", s, e);
+            }
+            write!(f, "{}", s)
+        }
+    }
+
+    impl XDomainReal {
+        pub fn new() -> XDomainReal {
+            XDomainReal { intervals: vec![] }
+        }
+
+        /// parse the domain, eg. "[1.5,3.25] ]4,+infinity[ 0.5", where a single value v is [v,v]
+        pub fn from_string(domain: &str) -> Result<XDomainReal, Xcsp3Error> {
+            let mut ret = XDomainReal::new();
+            let domain = domain.replace(" ,", ",").replace(", ", ",");
+            for d in domain.split_whitespace() {
+                let err = Xcsp3Error::get_domain_real_error(&format!(
+                    "parse the real interval {} error, ",
+                    d
+                ));
+                let interval =
+                    if d.starts_with(['[', ']']) && d.ends_with(['[', ']']) && d.len() > 2 {
+                        match d[1..d.len() - 1].split_once(',') {
+                            Some((l, r)) => {
+                                match (XRealInterval::parse_bound(l), XRealInterval::parse_bound(r))
+                                {
+                                    (Some(min), Some(max)) => XRealInterval::new(
+                                        min,
+                                        max,
+                                        d.starts_with(']'),
+                                        d.ends_with('['),
+                                    ),
+                                    _ => return Err(err),
+                                }
+                            }
+                            None => return Err(err),
+                        }
+                    } else {
+                        match XRealInterval::parse_bound(d) {
+                            Some(v) if v.is_finite() => XRealInterval::new(v, v, false, false),
+                            _ => return Err(err),
+                        }
+                    };
+                if interval.min > interval.max {
+                    return Err(err);
+                }
+                ret.add_interval(interval);
+            }
+            Ok(ret)
+        }
+
+        pub fn add_interval(&mut self, interval: XRealInterval) {
+            self.intervals.push(interval)
+        }
+
+        pub fn get_intervals(&self) -> &Vec<XRealInterval> {
+            &self.intervals
+        }
+
+        pub fn minimum(&self) -> f64 {
+            self.intervals
+                .iter()
+                .map(|e| e.min)
+                .fold(f64::INFINITY, f64::min)
+        }
+
+        pub fn maximum(&self) -> f64 {
+            self.intervals
+                .iter()
+                .map(|e| e.max)
+                .fold(f64::NEG_INFINITY, f64::max)
+        }
+
+        pub fn contains(&self, value: f64) -> bool {
+            self.intervals.iter().any(|e| e.contains(value))
+        }
+    }
+}
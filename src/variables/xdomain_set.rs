@@ -0,0 +1,96 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 12:10
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use std::fmt::{Display, Formatter};
+
+    /// the domain of a set variable, the values of required are in every set,
+    /// and the values of possible are the ones that can be in a set
+    #[derive(Clone)]
+    pub struct XDomainSet {
+        required: XDomainInteger,
+        possible: XDomainInteger,
+    }
+
+    impl Display for XDomainSet {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "required = {} possible = {}",
+                self.required, self.possible
+            )
+        }
+    }
+
+    impl XDomainSet {
+        pub fn from_string(required: &str, possible: &str) -> Result<XDomainSet, Xcsp3Error> {
+            Ok(XDomainSet::new(
+                XDomainInteger::from_string(required)?,
+                XDomainInteger::from_string(possible)?,
+            ))
+        }
+
+        pub fn new(required: XDomainInteger, possible: XDomainInteger) -> XDomainSet {
+            XDomainSet { required, possible }
+        }
+
+        /// the lower bound of the set
+        pub fn get_required(&self) -> &XDomainInteger {
+            &self.required
+        }
+
+        /// the upper bound of the set
+        pub fn get_possible(&self) -> &XDomainInteger {
+            &self.possible
+        }
+
+        /// the minimum cardinality of the set
+        pub fn min_card(&self) -> usize {
+            self.required.nb_values()
+        }
+
+        /// the maximum cardinality of the set
+        pub fn max_card(&self) -> usize {
+            self.possible.nb_values()
+        }
+    }
+}
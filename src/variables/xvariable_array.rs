@@ -53,6 +53,8 @@ pub mod xcsp3_core {
     pub struct XVariableArray {
         pub(crate) id: String,
         sizes: Vec<usize>,
+        /// the index of the first variable in each dimension
+        start_index: usize,
         domain: XDomainInteger,
     }
 
@@ -108,7 +110,7 @@ pub mod xcsp3_core {
                         "find_variable in XVariableArray error",
                     ));
                 }
-                Some(v) => match sizes_to_double_vec(&id[v..]) {
+                Some(v) => match sizes_to_double_vec(&id[v..], self.start_index) {
                     Ok((mut lower, mut upper)) => {
                         for i in 0..lower.len() {
                             if lower[i] == usize::MAX && upper[i] == usize::MAX {
@@ -123,7 +125,10 @@ pub mod xcsp3_core {
                         }
                         let all_variable = get_all_variables_between_lower_and_upper(lower, upper);
                         for size_vec in all_variable.iter() {
-                            ret.push((size_to_string(&id[..v], size_vec), &self.domain));
+                            ret.push((
+                                size_to_string(&id[..v], size_vec, self.start_index),
+                                &self.domain,
+                            ));
                         }
                     }
                     Err(e) => {
@@ -169,11 +174,17 @@ pub mod xcsp3_core {
             // return Ok(ret);
         }
 
-        pub fn new(id: &str, sizes: &str, domain: XDomainInteger) -> Result<Self, Xcsp3Error> {
+        pub fn new(
+            id: &str,
+            sizes: &str,
+            domain: XDomainInteger,
+            start_index: usize,
+        ) -> Result<Self, Xcsp3Error> {
             match sizes_to_vec(sizes) {
                 Ok((size_vec, _)) => Ok(XVariableArray {
                     id: id.to_string(),
                     sizes: size_vec,
+                    start_index,
                     domain,
                 }),
                 Err(e) => Err(e),
@@ -39,6 +39,8 @@
 pub mod xcsp3_core {
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xdomain_real::xcsp3_core::XDomainReal;
+    use crate::variables::xdomain_set::xcsp3_core::XDomainSet;
     use crate::variables::xvariable_type::xcsp3_core::XVariableType;
     use std::collections::HashMap;
     use std::fmt::{Display, Formatter};
@@ -127,10 +129,42 @@ pub mod xcsp3_core {
             }
         }
 
-        pub fn build_variable_array(&mut self, id: &str, sizes: &str, domain_string: &str) {
-            match XDomainInteger::from_string(domain_string) {
+        /// parse the startIndex of an array or a tree, 0 by default
+        fn parse_start_index(start_index: &str) -> Result<usize, Xcsp3Error> {
+            if start_index.is_empty() {
+                return Ok(0);
+            }
+            match start_index.trim().parse::<usize>() {
+                Ok(n) => Ok(n),
+                Err(_) => Err(Xcsp3Error::get_variable_size_invalid_error(
+                    &("parse the start index error, ".to_owned() + start_index),
+                )),
+            }
+        }
+
+        pub fn build_variable_array(
+            &mut self,
+            id: &str,
+            sizes: &str,
+            domain_string: &str,
+            symbolic: &str,
+            start_index: &str,
+        ) {
+            let start_index = match Self::parse_start_index(start_index) {
+                Ok(n) => n,
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                    return;
+                }
+            };
+            let domain = if symbolic.eq("symbolic") {
+                Ok(XDomainInteger::from_symbolic(domain_string))
+            } else {
+                XDomainInteger::from_string(domain_string)
+            };
+            match domain {
                 Ok(domain) => {
-                    let array = XVariableType::new_array(id, sizes, domain);
+                    let array = XVariableType::new_array(id, sizes, domain, start_index);
                     match array {
                         XVariableType::XVariableArray(_) => {
                             self.id_to_index
@@ -150,14 +184,109 @@ pub mod xcsp3_core {
             };
         }
 
+        /// build a set variable from its required and possible values
+        pub fn build_variable_set(&mut self, id: &str, required: &str, possible: &str) {
+            match XDomainSet::from_string(required, possible) {
+                Ok(domain) => {
+                    let var = XVariableType::new_set(id, domain);
+                    self.id_to_index.insert(var.get_id(), self.variables.len());
+                    self.variables.push(var);
+                }
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                }
+            }
+        }
+
+        pub fn build_variable_set_array(
+            &mut self,
+            id: &str,
+            sizes: &str,
+            required: &str,
+            possible: &str,
+            start_index: &str,
+        ) {
+            let start_index = match Self::parse_start_index(start_index) {
+                Ok(n) => n,
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                    return;
+                }
+            };
+            match XDomainSet::from_string(required, possible) {
+                Ok(domain) => {
+                    let array = XVariableType::new_set_array(id, sizes, domain, start_index);
+                    if let XVariableType::XVariableSetArray(_) = array {
+                        self.id_to_index
+                            .insert(array.get_id(), self.variables.len());
+                    }
+                    self.variables.push(array);
+                }
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                }
+            }
+        }
+
+        /// build a real variable, eg. the domain is [1.5,3.25]
+        pub fn build_variable_real(&mut self, id: &str, domain_string: &str) {
+            match XDomainReal::from_string(domain_string) {
+                Ok(domain) => {
+                    let var = XVariableType::new_real(id, domain);
+                    self.id_to_index.insert(var.get_id(), self.variables.len());
+                    self.variables.push(var);
+                }
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                }
+            }
+        }
+
+        pub fn build_variable_real_array(
+            &mut self,
+            id: &str,
+            sizes: &str,
+            domain_string: &str,
+            start_index: &str,
+        ) {
+            let start_index = match Self::parse_start_index(start_index) {
+                Ok(n) => n,
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                    return;
+                }
+            };
+            match XDomainReal::from_string(domain_string) {
+                Ok(domain) => {
+                    let array = XVariableType::new_real_array(id, sizes, domain, start_index);
+                    if let XVariableType::XVariableRealArray(_) = array {
+                        self.id_to_index
+                            .insert(array.get_id(), self.variables.len());
+                    }
+                    self.variables.push(array);
+                }
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                }
+            }
+        }
+
         pub fn build_variable_tree(
             &mut self,
             id: &str,
             sizes: &str,
             domain_for: Vec<&String>,
             domain_value: Vec<&String>,
+            start_index: &str,
         ) {
-            let tree = XVariableType::new_tree(id, sizes, domain_for, domain_value);
+            let start_index = match Self::parse_start_index(start_index) {
+                Ok(n) => n,
+                Err(e) => {
+                    self.variables.push(XVariableType::XVariableNone(e));
+                    return;
+                }
+            };
+            let tree = XVariableType::new_tree(id, sizes, domain_for, domain_value, start_index);
             match tree {
                 Ok(tree) => {
                     self.id_to_index.insert(tree.get_id(), self.variables.len());
@@ -182,6 +311,18 @@ pub mod xcsp3_core {
             }
         }
 
+        /// return the symbol of the value of the integer variable id, eg. x[2], None if the variable is not symbolic
+        pub fn get_symbolic(&self, id: &str, value: i64) -> Option<&str> {
+            let id = id.to_string();
+            match self.construct_scope(&[&id]) {
+                Ok(scope) => match scope.first() {
+                    Some((_, domain)) => domain.get_symbolic(value),
+                    None => None,
+                },
+                Err(_) => None,
+            }
+        }
+
         ///construct the scope from XVariableSet, when scope is equal to %x, where x is an i32 number, return empty tuple
         pub fn construct_scope(
             &self,
@@ -225,5 +366,47 @@ pub mod xcsp3_core {
             }
             Ok(ret)
         }
+
+        ///construct the scope of set variables from XVariableSet
+        pub fn construct_set_scope(
+            &self,
+            scope_str: &[&String],
+        ) -> Result<Vec<(String, &XDomainSet)>, Xcsp3Error> {
+            let mut ret: Vec<(String, &XDomainSet)> = vec![];
+            for e in scope_str.iter() {
+                match self.find_variable(e)? {
+                    XVariableType::XVariableSetVar(s) => ret.push((s.id.clone(), &s.domain)),
+                    XVariableType::XVariableSetArray(a) => ret.extend(a.find_variable(e)?),
+                    _ => {
+                        return Err(Xcsp3Error::get_variable_not_found_error(&format!(
+                            "the variable {} is not a set variable, ",
+                            e
+                        )));
+                    }
+                }
+            }
+            Ok(ret)
+        }
+
+        ///construct the scope of real variables from XVariableSet
+        pub fn construct_real_scope(
+            &self,
+            scope_str: &[&String],
+        ) -> Result<Vec<(String, &XDomainReal)>, Xcsp3Error> {
+            let mut ret: Vec<(String, &XDomainReal)> = vec![];
+            for e in scope_str.iter() {
+                match self.find_variable(e)? {
+                    XVariableType::XVariableReal(r) => ret.push((r.id.clone(), &r.domain)),
+                    XVariableType::XVariableRealArray(a) => ret.extend(a.find_variable(e)?),
+                    _ => {
+                        return Err(Xcsp3Error::get_variable_not_found_error(&format!(
+                            "the variable {} is not a real variable, ",
+                            e
+                        )));
+                    }
+                }
+            }
+            Ok(ret)
+        }
     }
 }
@@ -0,0 +1,132 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 12:26
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_core {
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::utils::utils_functions::xcsp3_utils::{
+        get_all_variables_between_lower_and_upper, size_to_string, sizes_to_double_vec,
+        sizes_to_vec,
+    };
+    use crate::variables::xdomain_set::xcsp3_core::XDomainSet;
+    use std::fmt::{Display, Formatter};
+
+    /// an array of set variables, all of them have the same domain
+    #[derive(Clone)]
+    pub struct XVariableSetArray {
+        pub(crate) id: String,
+        sizes: Vec<usize>,
+        /// the index of the first variable in each dimension
+        start_index: usize,
+        domain: XDomainSet,
+    }
+
+    impl Display for XVariableSetArray {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            let mut ret: String = String::default();
+            for e in self.sizes.iter() {
+                ret.push('[');
+                ret.push_str(e.to_string().as_str());
+                ret.push(']');
+            }
+            write!(
+                f,
+                "XVariableSetArray: id = {},   size =  {} domain = {}",
+                self.id, ret, self.domain
+            )
+        }
+    }
+
+    impl XVariableSetArray {
+        pub fn find_variable(&self, id: &str) -> Result<Vec<(String, &XDomainSet)>, Xcsp3Error> {
+            let mut ret: Vec<(String, &XDomainSet)> = vec![];
+            match id.find('[') {
+                None => {
+                    return Err(Xcsp3Error::get_variable_size_invalid_error(
+                        "find_variable in XVariableSetArray error",
+                    ));
+                }
+                Some(v) => {
+                    let (mut lower, mut upper) = sizes_to_double_vec(&id[v..], self.start_index)?;
+                    if lower.len() != self.sizes.len() {
+                        return Err(Xcsp3Error::get_variable_size_invalid_error(
+                            "find_variable in XVariableSetArray error",
+                        ));
+                    }
+                    for i in 0..lower.len() {
+                        if lower[i] == usize::MAX && upper[i] == usize::MAX {
+                            lower[i] = 0;
+                            upper[i] = self.sizes[i] - 1;
+                        }
+                        if lower[i] > upper[i] || upper[i] >= self.sizes[i] {
+                            return Err(Xcsp3Error::get_variable_size_invalid_error(
+                                "find_variable in XVariableSetArray error",
+                            ));
+                        }
+                    }
+                    let all_variable = get_all_variables_between_lower_and_upper(lower, upper);
+                    for size_vec in all_variable.iter() {
+                        ret.push((
+                            size_to_string(&id[..v], size_vec, self.start_index),
+                            &self.domain,
+                        ));
+                    }
+                }
+            }
+            Ok(ret)
+        }
+
+        pub fn new(
+            id: &str,
+            sizes: &str,
+            domain: XDomainSet,
+            start_index: usize,
+        ) -> Result<Self, Xcsp3Error> {
+            match sizes_to_vec(sizes) {
+                Ok((size_vec, _)) => Ok(XVariableSetArray {
+                    id: id.to_string(),
+                    sizes: size_vec,
+                    start_index,
+                    domain,
+                }),
+                Err(e) => Err(e),
+            }
+        }
+    }
+}
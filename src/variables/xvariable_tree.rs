@@ -56,6 +56,8 @@ pub mod xcsp3_core {
         others: XVariableTreeNode,
         pub(crate) id: String,
         sizes: Vec<usize>,
+        /// the index of the first variable in each dimension
+        start_index: usize,
     }
 
     impl XVariableTree {
@@ -81,18 +83,26 @@ pub mod xcsp3_core {
                         "find_variable in XVariableTree error",
                     ));
                 }
-                Some(v) => match sizes_to_double_vec(&id[v..]) {
+                Some(v) => match sizes_to_double_vec(&id[v..], self.start_index) {
                     Ok((mut lower, mut upper)) => {
                         for i in 0..lower.len() {
                             if lower[i] == usize::MAX && upper[i] == usize::MAX {
                                 lower[i] = 0;
                                 upper[i] = self.sizes[i] - 1;
                             }
+                            if lower[i] > upper[i] || upper[i] >= self.sizes[i] {
+                                return Err(Xcsp3Error::get_variable_size_invalid_error(
+                                    "find_variable in XVariableTree error",
+                                ));
+                            }
                         }
                         let all_variable = get_all_variables_between_lower_and_upper(lower, upper);
                         for size_vec in all_variable.iter() {
                             let node = self.get_node_by_vec(size_vec);
-                            ret.push((size_to_string(&id[..v], size_vec), &node.domain));
+                            ret.push((
+                                size_to_string(&id[..v], size_vec, self.start_index),
+                                &node.domain,
+                            ));
                         }
                     }
                     Err(e) => {
@@ -108,6 +118,7 @@ pub mod xcsp3_core {
             sizes: &str,
             domain_for: Vec<&String>,
             domain_value: Vec<&String>,
+            start_index: usize,
         ) -> Result<Self, Xcsp3Error> {
             match sizes_to_vec(sizes) {
                 Ok((size_vec, _)) => {
@@ -124,7 +135,7 @@ pub mod xcsp3_core {
                                         domain_for[i].split_whitespace().collect();
                                     for e in for_strs.iter() {
                                         let for_str = e.to_string().replace(id, "");
-                                        match sizes_to_double_vec(&for_str) {
+                                        match sizes_to_double_vec(&for_str, start_index) {
                                             Ok((lower, upper)) => {
                                                 nodes.push(XVariableTreeNode::new(
                                                     lower,
@@ -148,6 +159,7 @@ pub mod xcsp3_core {
                     Ok(XVariableTree {
                         id: id.to_string(),
                         sizes: size_vec,
+                        start_index,
                         others: XVariableTreeNode::new_other(others_domain),
                         nodes,
                     })
@@ -176,7 +188,7 @@ pub mod xcsp3_core {
             }
             true
         }
-        pub fn to_string(&self, id: &str) -> String {
+        pub fn to_string(&self, id: &str, start_index: usize) -> String {
             let mut ret = format!("[for = {}", id);
             if self.is_other {
                 ret.push_str("[others]..");
@@ -187,12 +199,12 @@ pub mod xcsp3_core {
                         if self.lower[i] == usize::MAX {
                             ret.push('*');
                         } else {
-                            ret.push_str(&self.lower[i].to_string());
+                            ret.push_str(&(self.lower[i] + start_index).to_string());
                         }
                     } else {
-                        ret.push_str(&self.lower[i].to_string());
+                        ret.push_str(&(self.lower[i] + start_index).to_string());
                         ret.push_str("..");
-                        ret.push_str(&self.upper[i].to_string());
+                        ret.push_str(&(self.upper[i] + start_index).to_string());
                     }
 
                     ret.push(']');
@@ -237,7 +249,7 @@ pub mod xcsp3_core {
             }
             ret.push_str("nodes = ");
             for e in self.nodes.iter() {
-                ret += &e.to_string(&self.id);
+                ret += &e.to_string(&self.id, self.start_index);
                 ret += "]  ";
             }
 
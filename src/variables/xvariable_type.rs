@@ -41,8 +41,14 @@
 pub mod xcsp3_core {
     use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
     use crate::variables::xdomain::xcsp3_core::XDomainInteger;
+    use crate::variables::xdomain_real::xcsp3_core::XDomainReal;
+    use crate::variables::xdomain_set::xcsp3_core::XDomainSet;
     use crate::variables::xvariable_array::xcsp3_core::XVariableArray;
     use crate::variables::xvariable_int::xcsp3_core::XVariableInt;
+    use crate::variables::xvariable_real::xcsp3_core::XVariableReal;
+    use crate::variables::xvariable_real_array::xcsp3_core::XVariableRealArray;
+    use crate::variables::xvariable_set_array::xcsp3_core::XVariableSetArray;
+    use crate::variables::xvariable_set_var::xcsp3_core::XVariableSetVar;
     use std::fmt::{Display, Formatter};
     // use crate::variables::xvariable_trait::xcsp3_core::XVariableTrait;
     use crate::variables::xvariable_tree::xcsp3_core::XVariableTree;
@@ -53,6 +59,10 @@ pub mod xcsp3_core {
         XVariableArray(XVariableArray),
         XVariableInt(XVariableInt),
         XVariableTree(XVariableTree),
+        XVariableSetVar(XVariableSetVar),
+        XVariableSetArray(XVariableSetArray),
+        XVariableReal(XVariableReal),
+        XVariableRealArray(XVariableRealArray),
     }
 
     impl XVariableType {
@@ -60,20 +70,58 @@ pub mod xcsp3_core {
             XVariableType::XVariableInt(XVariableInt::new(id.to_string(), domain))
         }
 
-        pub fn new_array(id: &str, sizes: &str, domain: XDomainInteger) -> XVariableType {
-            match XVariableArray::new(id, sizes, domain) {
+        pub fn new_array(
+            id: &str,
+            sizes: &str,
+            domain: XDomainInteger,
+            start_index: usize,
+        ) -> XVariableType {
+            match XVariableArray::new(id, sizes, domain, start_index) {
                 Ok(array) => XVariableType::XVariableArray(array),
                 Err(e) => XVariableType::XVariableNone(e),
             }
         }
 
+        pub fn new_set(id: &str, domain: XDomainSet) -> XVariableType {
+            XVariableType::XVariableSetVar(XVariableSetVar::new(id.to_string(), domain))
+        }
+
+        pub fn new_set_array(
+            id: &str,
+            sizes: &str,
+            domain: XDomainSet,
+            start_index: usize,
+        ) -> XVariableType {
+            match XVariableSetArray::new(id, sizes, domain, start_index) {
+                Ok(array) => XVariableType::XVariableSetArray(array),
+                Err(e) => XVariableType::XVariableNone(e),
+            }
+        }
+
+        pub fn new_real(id: &str, domain: XDomainReal) -> XVariableType {
+            XVariableType::XVariableReal(XVariableReal::new(id.to_string(), domain))
+        }
+
+        pub fn new_real_array(
+            id: &str,
+            sizes: &str,
+            domain: XDomainReal,
+            start_index: usize,
+        ) -> XVariableType {
+            match XVariableRealArray::new(id, sizes, domain, start_index) {
+                Ok(array) => XVariableType::XVariableRealArray(array),
+                Err(e) => XVariableType::XVariableNone(e),
+            }
+        }
+
         pub fn new_tree(
             id: &str,
             sizes: &str,
             domain_for: Vec<&String>,
             domain_value: Vec<&String>,
+            start_index: usize,
         ) -> Result<Self, Xcsp3Error> {
-            match XVariableTree::new(id, sizes, domain_for, domain_value) {
+            match XVariableTree::new(id, sizes, domain_for, domain_value, start_index) {
                 Ok(t) => Ok(XVariableType::XVariableTree(t)),
                 Err(e) => Err(e),
             }
@@ -84,6 +132,10 @@ pub mod xcsp3_core {
                 XVariableType::XVariableArray(v) => v.id.clone(),
                 XVariableType::XVariableInt(v) => v.id.clone(),
                 XVariableType::XVariableTree(v) => v.id.clone(),
+                XVariableType::XVariableSetVar(v) => v.id.clone(),
+                XVariableType::XVariableSetArray(v) => v.id.clone(),
+                XVariableType::XVariableReal(v) => v.id.clone(),
+                XVariableType::XVariableRealArray(v) => v.id.clone(),
                 _ => String::default(),
             }
         }
@@ -98,6 +150,10 @@ pub mod xcsp3_core {
                     XVariableType::XVariableArray(a) => a.to_string(),
                     XVariableType::XVariableInt(i) => i.to_string(),
                     XVariableType::XVariableTree(t) => t.to_string(),
+                    XVariableType::XVariableSetVar(s) => s.to_string(),
+                    XVariableType::XVariableSetArray(s) => s.to_string(),
+                    XVariableType::XVariableReal(r) => r.to_string(),
+                    XVariableType::XVariableRealArray(r) => r.to_string(),
                     _ => {
                         String::from(
                             "XVariableNone: there must be an error when parse this variable.",
@@ -0,0 +1,149 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 17:00
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_xml {
+    use serde::Deserialize;
+
+    /**
+    ```xml
+    <static [ order="(intVal wspace)+" ]> (intVar wspace)+ </static>
+    <random> (intVar wspace)+ </random>
+    <min type="identifier"> (intVar wspace)+ </min>
+    <max type="identifier"> (intVar wspace)+ </max>
+    ```
+     */
+    #[derive(Deserialize, Debug)]
+    pub enum HeuristicPart {
+        #[serde(rename = "static")]
+        Static {
+            #[serde(rename = "@order", default)]
+            order: String,
+            #[serde(rename = "$value", default)]
+            list: String,
+        },
+        #[serde(rename = "random")]
+        Random {
+            #[serde(rename = "$value", default)]
+            list: String,
+        },
+        #[serde(rename = "min")]
+        Min {
+            #[serde(rename = "@type", default)]
+            r#type: String,
+            #[serde(rename = "$value", default)]
+            list: String,
+        },
+        #[serde(rename = "max")]
+        Max {
+            #[serde(rename = "@type", default)]
+            r#type: String,
+            #[serde(rename = "$value", default)]
+            list: String,
+        },
+    }
+
+    /**
+    ```xml
+    <varHeuristic [ lc="integer" ]> (<static.../> | <random.../> | <min.../> | <max.../>)+ </varHeuristic>
+    <valHeuristic> (<static.../> | <random.../> | <min.../> | <max.../>)+ </valHeuristic>
+    ```
+     */
+    #[derive(Deserialize, Debug)]
+    pub struct Heuristic {
+        #[serde(rename = "@lc", default)]
+        pub lc: String,
+        #[serde(rename = "$value", default)]
+        pub parts: Vec<HeuristicPart>,
+    }
+
+    /**
+    ```xml
+    <filtering [ type="identifier" ]> (identifier wspace)* </filtering>
+    ```
+     */
+    #[derive(Deserialize, Debug)]
+    pub struct Filtering {
+        #[serde(rename = "@type", default)]
+        pub r#type: String,
+        #[serde(rename = "$value", default)]
+        pub constraints: String,
+    }
+
+    /**
+    ```xml
+    <prepro [ consistency="identifier" ]> (identifier wspace)* </prepro>
+    ```
+     */
+    #[derive(Deserialize, Debug)]
+    pub struct Prepro {
+        #[serde(rename = "@consistency", default)]
+        pub consistency: String,
+        #[serde(rename = "$value", default)]
+        pub constraints: String,
+    }
+
+    /**
+    ```xml
+    <annotations>
+      [<decision> (intVar wspace)+ </decision>]
+      [<output> (intVar wspace)+ </output>]
+      [<varHeuristic.../>]
+      [<valHeuristic.../>]
+      (<filtering.../>)*
+      (<prepro.../>)*
+    </annotations>
+    ```
+     */
+    #[derive(Deserialize, Debug)]
+    pub struct Annotations {
+        #[serde(rename = "decision", default)]
+        pub decision: String,
+        #[serde(rename = "output", default)]
+        pub output: String,
+        #[serde(rename = "varHeuristic", default)]
+        pub var_heuristic: Option<Heuristic>,
+        #[serde(rename = "valHeuristic", default)]
+        pub val_heuristic: Option<Heuristic>,
+        #[serde(rename = "filtering", default)]
+        pub filtering: Vec<Filtering>,
+        #[serde(rename = "prepro", default)]
+        pub prepro: Vec<Prepro>,
+    }
+}
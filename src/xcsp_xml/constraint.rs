@@ -67,6 +67,14 @@ pub mod xcsp3_xml {
         pub closed: String,
     }
 
+    #[derive(Deserialize, Debug, Default)]
+    pub struct ListWithCovered {
+        #[serde(rename = "$value", default)]
+        pub vars: String,
+        #[serde(rename = "@covered", default)]
+        pub covered: String,
+    }
+
     #[derive(Deserialize, Debug, Default)]
     pub struct ListWithStartIndex {
         // #[serde(rename = "constraint", default)]
@@ -39,12 +39,16 @@
  */
 
 pub mod xcsp3_xml {
+    use crate::data_structs::xreification::xcsp3_core::XReification;
     use crate::xcsp_xml::constraint::xcsp3_xml::{
-        ListWithClosed, ListWithOffset, ListWithStartIndex,
+        ListWithClosed, ListWithCovered, ListWithOffset, ListWithStartIndex,
     };
     use crate::xcsp_xml::constraint_block::xcsp3_xml::ConstraintBlock;
     use crate::xcsp_xml::constraint_group::xcsp3_xml::ConstraintGroup;
-    use serde::Deserialize;
+    use serde::de::value::MapDeserializer;
+    use serde::de::{IgnoredAny, MapAccess, Visitor};
+    use serde::{Deserialize, Deserializer};
+    use std::fmt::Formatter;
 
     #[derive(Deserialize, Debug, Default)]
     pub enum ConstraintType {
@@ -146,7 +150,9 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "allDifferent")]
         AllDifferent {
-            #[serde(rename = "$value", default)]
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "$text", default)]
             vars: String,
             #[serde(rename = "list", default)]
             list: Box<[String]>,
@@ -182,28 +188,45 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "allEqual")]
         AllEqual {
-            #[serde(rename = "$value", default)]
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "$text", default)]
             vars: String,
             #[serde(rename = "list", default)]
             list: Vec<String>,
         },
 
         /**
+        syntax.
+        ```xml
+        <circuit>
+          <list  [ startIndex="integer" ]> (intVar wspace)2+ </list>
+          [<size> intVal | intVar </size>]
+        </circuit>
+        <circuit> (intVar wspace)2+ </circuit>  <!-- Simplified Form -->
+        ```
 
-        syntax
-           ```xm
-
-           ```
-
-         eg.
-         ```xml
-
-         ```
+        eg.
+        ```xml
+        <circuit>
+          x1 x2 x3 x4
+        </circuit>
+        <circuit>
+          <list startIndex="1"> y1 y2 y3 y4 </list>
+          <size> 2 </size>
+        </circuit>
+        ```
          */
         #[serde(rename = "circuit")]
         Circuit {
-            #[serde(rename = "$value")]
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "$text", default)]
             vars: String,
+            #[serde(rename = "list", default)]
+            list: ListWithStartIndex,
+            #[serde(rename = "size", default)]
+            size: String,
         },
 
         /**
@@ -231,6 +254,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "ordered")]
         Ordered {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "operator", default)]
@@ -265,7 +290,9 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "intension")]
         Intension {
-            #[serde(rename = "$value", default)]
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "$text", default)]
             value: String,
             #[serde(rename = "function", default)]
             function: String,
@@ -289,16 +316,31 @@ pub mod xcsp3_xml {
           <list> y1 y2 y3 </list>
           <supports> (0,1,0)(1,0,0)(1,1,0)(1,1,1) </supports>
         </extension>
+        ```
+
+        a soft extension gives the costs of tuples, the other tuples cost defaultCost.
+        ```xml
+        <extension type="soft" defaultCost="0" ub="10">
+          <list> x y </list>
+          <tuples> (0,1):5 (1,*):3 </tuples>
+        </extension>
         ```
          */
         #[serde(rename = "extension")]
         Extension {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "@defaultCost", default)]
+            default_cost: String,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "supports", default)]
             supports: String,
             #[serde(rename = "conflicts", default)]
             conflicts: String,
+            /// the tuples with their costs of a soft extension, eg. (0,1):5 (1,1):3
+            #[serde(rename = "tuples", default)]
+            tuples: String,
         },
 
         /**
@@ -326,6 +368,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "regular")]
         Regular {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "transitions", default)]
@@ -359,6 +403,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "mdd")]
         Mdd {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "transitions", default)]
@@ -386,6 +432,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "sum")]
         Sum {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "condition", default)]
@@ -435,6 +483,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "count")]
         Count {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             // #[serde(rename = "@id", default)]
             // id: String,
             #[serde(rename = "list", default)]
@@ -474,6 +524,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "nValues")]
         NValues {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "except", default)]
@@ -508,6 +560,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "cardinality")]
         Cardinality {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             list: String,
             #[serde(rename = "values", default)]
@@ -539,6 +593,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "minimum")]
         Minimum {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             list: String,
             #[serde(rename = "condition", default)]
@@ -568,6 +624,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "maximum")]
         Maximum {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             list: String,
             #[serde(rename = "condition", default)]
@@ -599,6 +657,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "element")]
         Element {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: ListWithStartIndex,
             #[serde(rename = "value", default)]
@@ -629,6 +689,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "stretch")]
         Stretch {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "values", default)]
@@ -662,6 +724,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "noOverlap")]
         NoOverlap {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "origins", default)]
             origins: String,
             #[serde(rename = "lengths", default)]
@@ -704,6 +768,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "cumulative")]
         Cumulative {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "origins", default)]
             origins: String,
             #[serde(rename = "lengths", default)]
@@ -741,6 +807,8 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "instantiation")]
         Instantiation {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             vars: String,
             #[serde(rename = "values", default)]
@@ -776,16 +844,105 @@ pub mod xcsp3_xml {
         ```
          */
         #[serde(rename = "slide")]
-        Slide {
-            // #[serde(rename = "@id", default)]
-            // id: String,
-            #[serde(rename = "@circular", default)]
-            circular: String,
-            #[serde(rename = "list", default)]
-            list: ListWithOffset,
-            #[serde(rename = "$value", default)]
-            constraints: Box<ConstraintType>,
-        },
+        Slide(ConstraintChildren),
+
+        /**
+        syntax.
+        ```xml
+        <and>
+          (<constraint.../>)2+
+        </and>
+        ```
+
+        eg.
+        ```xml
+        <and>
+          <intension> ge(x,y) </intension>
+          <allDifferent> x y z </allDifferent>
+        </and>
+        ```
+         */
+        #[serde(rename = "and")]
+        And(ConstraintChildren),
+
+        /**
+        syntax.
+        ```xml
+        <or>
+          (<constraint.../>)2+
+        </or>
+        ```
+
+        eg.
+        ```xml
+        <or>
+          <intension> eq(x,0) </intension>
+          <intension> eq(y,0) </intension>
+        </or>
+        ```
+         */
+        #[serde(rename = "or")]
+        Or(ConstraintChildren),
+
+        /**
+        syntax.
+        ```xml
+        <not>
+          <constraint.../>
+        </not>
+        ```
+
+        eg.
+        ```xml
+        <not>
+          <allEqual> x y z </allEqual>
+        </not>
+        ```
+         */
+        #[serde(rename = "not")]
+        Not(ConstraintChildren),
+
+        /**
+        syntax.
+        ```xml
+        <ifThen>
+          <constraint.../>
+          <constraint.../>
+        </ifThen>
+        ```
+
+        eg.
+        ```xml
+        <ifThen>
+          <intension> eq(b,1) </intension>
+          <allDifferent> x y z </allDifferent>
+        </ifThen>
+        ```
+         */
+        #[serde(rename = "ifThen")]
+        IfThen(ConstraintChildren),
+
+        /**
+        syntax.
+        ```xml
+        <ifThenElse>
+          <constraint.../>
+          <constraint.../>
+          <constraint.../>
+        </ifThenElse>
+        ```
+
+        eg.
+        ```xml
+        <ifThenElse>
+          <intension> eq(b,1) </intension>
+          <allDifferent> x y z </allDifferent>
+          <allEqual> x y z </allEqual>
+        </ifThenElse>
+        ```
+         */
+        #[serde(rename = "ifThenElse")]
+        IfThenElse(ConstraintChildren),
 
         /**
         syntax.
@@ -815,86 +972,510 @@ pub mod xcsp3_xml {
          */
         #[serde(rename = "channel")]
         Channel {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
             #[serde(rename = "list", default)]
             lists: Box<[ListWithStartIndex]>,
             #[serde(rename = "value", default)]
             with_value: String,
-            #[serde(rename = "$value", default)]
+            #[serde(rename = "$text", default)]
             simplified_list: String,
         },
 
-        // #[serde(rename = "allDistant")]
-        // AllDistant {
-        //     #[serde(rename = "list", default)]
-        //     vars: String,
-        //     #[serde(rename = "condition", default)]
-        //     condition: String,
-        // },
-
-        // #[serde(rename = "precedence")]
-        // Precedence {
-        //     #[serde(rename = "list", default)]
-        //     vars: String,
-        //     #[serde(rename = "values", default)]
-        //     values: String,
-        // },
-
-        // #[serde(rename = "balance")]
-        // Balance {
-        //     #[serde(rename = "list", default)]
-        //     vars: String,
-        //     #[serde(rename = "condition", default)]
-        //     condition: String,
-        //     #[serde(rename = "values", default)]
-        //     values: String,
-        // },
-
-        // #[serde(rename = "spread")]
-        // Spread {
-        //     #[serde(rename = "list", default)]
-        //     vars: String,
-        //     #[serde(rename = "condition", default)]
-        //     condition: String,
-        //     #[serde(rename = "total", default)]
-        //     total: String,
-        // },
-
-        // #[serde(rename = "deviation")]
-        // Deviation {
-        //     #[serde(rename = "list", default)]
-        //     vars: String,
-        //     #[serde(rename = "condition", default)]
-        //     condition: String,
-        //     #[serde(rename = "total", default)]
-        //     total: String,
-        // },
-
-        // #[serde(rename = "binPacking")]
-        // BinPacking {
-        //     #[serde(rename = "list", default)]
-        //     vars: String,
-        //     #[serde(rename = "sizes", default)]
-        //     sizes: String,
-        //     #[serde(rename = "condition", default)]
-        //     condition: String,
-        // },
-
-        // #[serde(rename = "lex")]
-        // Lex {
-        //     #[serde(rename = "matrix", default)]
-        //     matrix: String,
-        //     #[serde(rename = "operator", default)]
-        //     operator: String,
-        // },
-
-        // #[serde(rename = "clause")]
-        // Clause {
-        //     #[serde(rename = "$value", default)]
-        //     vars: String,
-        //     #[serde(rename = "list", default)]
-        //     list: Box<[String]>,
-        // },
+        /**
+        syntax.
+        ```xml
+        <lex>
+          (<list> (intVar wspace)2+ </list>)2+
+          <operator> "lt" | "le" | "gt" | "ge" </operator>
+        </lex>
+        <lex>
+          <matrix> ("(" (intVar ",")+ ")")2+ </matrix>
+          <operator> "lt" | "le" | "gt" | "ge" </operator>
+        </lex>
+        ```
+
+        eg.
+        ```xml
+        <lex>
+          <list> x1 x2 x3 x4 </list>
+          <list> y1 y2 y3 y4 </list>
+          <operator> lt </operator>
+        </lex>
+        <lex>
+          <matrix>
+            (z1,z2,z3)
+            (z4,z5,z6)
+            (z7,z8,z9)
+          </matrix>
+          <operator> lt </operator>
+        </lex>
+        ```
+         */
+        #[serde(rename = "lex")]
+        Lex {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            lists: Vec<String>,
+            #[serde(rename = "matrix", default)]
+            matrix: String,
+            #[serde(rename = "operator", default)]
+            operator: String,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <precedence>
+          <list> (intVar wspace)2+ </list>
+          [<values [covered="boolean"]> (intVal wspace)2+ </values>]
+        </precedence>
+        ```
+
+        eg.
+        ```xml
+        <precedence>
+          <list> x1 x2 x3 x4 </list>
+          <values> 4 0 1 </values>
+        </precedence>
+        <precedence>
+          <list> y1 y2 y3 y4 </list>
+          <values covered="true"> 0 1 2 </values>
+        </precedence>
+        ```
+         */
+        #[serde(rename = "precedence")]
+        Precedence {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "values", default)]
+            values: ListWithCovered,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <allDistant>
+          <list> (intVar wspace)2+ </list>
+          <condition> "(" operator "," operand ")" </condition>
+        </allDistant>
+        ```
+
+        eg.
+        ```xml
+        <allDistant>
+          <list> x1 x2 x3 x4 </list>
+          <condition> (ge,2) </condition>
+        </allDistant>
+        ```
+         */
+        #[serde(rename = "allDistant")]
+        AllDistant {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "condition", default)]
+            condition: String,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <balance>
+          <list> (intVar wspace)2+ </list>
+          [<values> (intVal wspace)+ </values>]
+          <condition> "(" operator "," operand ")" </condition>
+        </balance>
+        ```
+
+        eg.
+        ```xml
+        <balance>
+          <list> x1 x2 x3 x4 x5 x6 </list>
+          <values> 1 2 3 </values>
+          <condition> (le,1) </condition>
+        </balance>
+        ```
+         */
+        #[serde(rename = "balance")]
+        Balance {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "values", default)]
+            values: String,
+            #[serde(rename = "condition", default)]
+            condition: String,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <spread>
+          <list> (intVar wspace)2+ </list>
+          [<total> intVal | intVar </total>]
+          <condition> "(" operator "," operand ")" </condition>
+        </spread>
+        ```
+
+        eg.
+        ```xml
+        <spread>
+          <list> x1 x2 x3 x4 </list>
+          <total> 20 </total>
+          <condition> (le,s) </condition>
+        </spread>
+        ```
+         */
+        #[serde(rename = "spread")]
+        Spread {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "total", default)]
+            total: String,
+            #[serde(rename = "condition", default)]
+            condition: String,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <deviation>
+          <list> (intVar wspace)2+ </list>
+          [<total> intVal | intVar </total>]
+          <condition> "(" operator "," operand ")" </condition>
+        </deviation>
+        ```
+
+        eg.
+        ```xml
+        <deviation>
+          <list> x1 x2 x3 x4 </list>
+          <total> 20 </total>
+          <condition> (le,d) </condition>
+        </deviation>
+        ```
+         */
+        #[serde(rename = "deviation")]
+        Deviation {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "total", default)]
+            total: String,
+            #[serde(rename = "condition", default)]
+            condition: String,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <knapsack>
+          <list> (intVar wspace)2+ </list>
+          <weights> (intVal wspace)2+ </weights>
+          <profits> (intVal wspace)2+ </profits>
+          <condition> "(" operator "," operand ")" </condition>
+          <condition> "(" operator "," operand ")" </condition>
+        </knapsack>
+        ```
+
+        eg.
+        ```xml
+        <knapsack>
+          <list> i1 i2 i3 i4 </list>
+          <weights> 5 3 2 4 </weights>
+          <profits> 2 1 3 4 </profits>
+          <condition> (le,10) </condition>
+          <condition> (ge,z) </condition>
+        </knapsack>
+        ```
+         */
+        #[serde(rename = "knapsack")]
+        Knapsack {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "weights", default)]
+            weights: String,
+            #[serde(rename = "profits", default)]
+            profits: String,
+            #[serde(rename = "condition", default)]
+            conditions: Vec<String>,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <flow>
+          <list> (intVar wspace)2+ </list>
+          <balance> (intVal wspace)2+ </balance>
+          <arcs> ("(" intVal "," intVal ")")2+ </arcs>
+          [<weights> (intVal wspace)2+ </weights>
+          <condition> "(" operator "," operand ")" </condition>]
+        </flow>
+        ```
+
+        eg.
+        ```xml
+        <flow>
+          <list> x1 x2 x3 x4 </list>
+          <balance> 3 0 -3 </balance>
+          <arcs> (1,2)(1,3)(2,3)(3,1) </arcs>
+          <weights> 1 2 1 4 </weights>
+          <condition> (le,z) </condition>
+        </flow>
+        ```
+         */
+        #[serde(rename = "flow")]
+        Flow {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "balance", default)]
+            balance: String,
+            #[serde(rename = "arcs", default)]
+            arcs: String,
+            #[serde(rename = "weights", default)]
+            weights: String,
+            #[serde(rename = "condition", default)]
+            condition: String,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <binPacking>
+          <list> (intVar wspace)2+ </list>
+          <sizes> (intVal wspace)2+ </sizes>
+          <condition> "(" operator "," operand ")" </condition>
+        </binPacking>
+        <binPacking>
+          <list> (intVar wspace)2+ </list>
+          <sizes> (intVal wspace)2+ </sizes>
+          <limits> (intVal wspace)2+ | (intVar wspace)2+ </limits>
+        </binPacking>
+        <binPacking>
+          <list> (intVar wspace)2+ </list>
+          <sizes> (intVal wspace)2+ </sizes>
+          <loads> (intVar wspace)2+ </loads>
+        </binPacking>
+        ```
+
+        eg.
+        ```xml
+        <binPacking>
+          <list> b1 b2 b3 b4 </list>
+          <sizes> 2 5 3 4 </sizes>
+          <condition> (le,8) </condition>
+        </binPacking>
+        <binPacking>
+          <list> b1 b2 b3 b4 </list>
+          <sizes> 2 5 3 4 </sizes>
+          <loads> l0 l1 l2 </loads>
+        </binPacking>
+        ```
+         */
+        #[serde(rename = "binPacking")]
+        BinPacking {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "list", default)]
+            list: String,
+            #[serde(rename = "sizes", default)]
+            sizes: String,
+            #[serde(rename = "condition", default)]
+            condition: String,
+            #[serde(rename = "limits", default)]
+            limits: String,
+            #[serde(rename = "loads", default)]
+            loads: String,
+        },
+
+        /**
+        syntax.
+        ```xml
+        <clause>
+          <list> ((intVar | not(intVar)) wspace)+ </list>
+        </clause>
+        ```
+
+        eg.
+        ```xml
+        <clause>
+          <list> x1 not(x2) x3 </list>
+        </clause>
+        <clause> not(y1) not(y2) </clause>
+        ```
+         */
+        #[serde(rename = "clause")]
+        Clause {
+            #[serde(flatten)]
+            attributes: ConstraintAttributes,
+            #[serde(rename = "$text", default)]
+            vars: String,
+            #[serde(rename = "list", default)]
+            list: String,
+        },
         #[default]
         ConstraintNone,
     }
+    impl ConstraintType {
+        /// the attributes of the constraint, a group and a block have none
+        pub fn get_attributes(&self) -> Option<&ConstraintAttributes> {
+            match self {
+                ConstraintType::AllDifferent { attributes, .. }
+                | ConstraintType::AllEqual { attributes, .. }
+                | ConstraintType::Circuit { attributes, .. }
+                | ConstraintType::Ordered { attributes, .. }
+                | ConstraintType::Intension { attributes, .. }
+                | ConstraintType::Extension { attributes, .. }
+                | ConstraintType::Regular { attributes, .. }
+                | ConstraintType::Mdd { attributes, .. }
+                | ConstraintType::Sum { attributes, .. }
+                | ConstraintType::Count { attributes, .. }
+                | ConstraintType::NValues { attributes, .. }
+                | ConstraintType::Cardinality { attributes, .. }
+                | ConstraintType::Minimum { attributes, .. }
+                | ConstraintType::Maximum { attributes, .. }
+                | ConstraintType::Element { attributes, .. }
+                | ConstraintType::Stretch { attributes, .. }
+                | ConstraintType::NoOverlap { attributes, .. }
+                | ConstraintType::Cumulative { attributes, .. }
+                | ConstraintType::Instantiation { attributes, .. }
+                | ConstraintType::Channel { attributes, .. }
+                | ConstraintType::Lex { attributes, .. }
+                | ConstraintType::Precedence { attributes, .. }
+                | ConstraintType::AllDistant { attributes, .. }
+                | ConstraintType::Balance { attributes, .. }
+                | ConstraintType::Spread { attributes, .. }
+                | ConstraintType::Deviation { attributes, .. }
+                | ConstraintType::Knapsack { attributes, .. }
+                | ConstraintType::Flow { attributes, .. }
+                | ConstraintType::BinPacking { attributes, .. }
+                | ConstraintType::Clause { attributes, .. } => Some(attributes),
+                ConstraintType::Slide(c)
+                | ConstraintType::And(c)
+                | ConstraintType::Or(c)
+                | ConstraintType::Not(c)
+                | ConstraintType::IfThen(c)
+                | ConstraintType::IfThenElse(c) => Some(&c.attributes),
+                _ => None,
+            }
+        }
+
+        /// the reification attributes, which can be set on every constraint
+        pub fn get_reification(&self) -> Option<XReification> {
+            self.get_attributes()?.get_reification()
+        }
+
+        /// the attributes violationMeasure, violationCost and ub of a relaxed constraint, whose type is soft.
+        /// a soft extension is given by its defaultCost and its tuples with costs instead
+        pub fn get_soft(&self) -> Option<(&str, &str, &str)> {
+            match self {
+                ConstraintType::Extension { .. } => None,
+                _ => self.get_attributes()?.get_soft(),
+            }
+        }
+    }
+
+    /// the attributes which can be set on every constraint, the reification by reifiedBy, hreifiedFrom
+    /// and hreifiedTo, and the relaxation by type="soft", violationMeasure, violationCost and ub
+    #[derive(Deserialize, Debug, Default)]
+    pub struct ConstraintAttributes {
+        #[serde(rename = "@reifiedBy", default)]
+        pub reified_by: String,
+        #[serde(rename = "@hreifiedFrom", default)]
+        pub hreified_from: String,
+        #[serde(rename = "@hreifiedTo", default)]
+        pub hreified_to: String,
+        #[serde(rename = "@type", default)]
+        pub r#type: String,
+        #[serde(rename = "@violationMeasure", default)]
+        pub violation_measure: String,
+        #[serde(rename = "@violationCost", default)]
+        pub violation_cost: String,
+        #[serde(rename = "@ub", default)]
+        pub ub: String,
+    }
+
+    impl ConstraintAttributes {
+        pub fn get_reification(&self) -> Option<XReification> {
+            XReification::from_str(&self.reified_by, &self.hreified_from, &self.hreified_to)
+        }
+
+        pub fn is_soft(&self) -> bool {
+            self.r#type.trim() == "soft"
+        }
+
+        pub fn get_soft(&self) -> Option<(&str, &str, &str)> {
+            if self.is_soft() {
+                Some((&self.violation_measure, &self.violation_cost, &self.ub))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// the attributes and the child constraints of a meta-constraint, or the attributes, the list and
+    /// the template of a slide. quick-xml can not flatten the attributes next to $value children,
+    /// so this is deserialized by hand, the attributes are then deserialized into ConstraintAttributes
+    #[derive(Debug, Default)]
+    pub struct ConstraintChildren {
+        pub attributes: ConstraintAttributes,
+        /// the attribute circular of a slide
+        pub circular: String,
+        /// the list of a slide
+        pub list: ListWithOffset,
+        pub constraints: Vec<ConstraintType>,
+    }
+
+    impl<'de> Deserialize<'de> for ConstraintChildren {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct ChildrenVisitor;
+
+            impl<'de> Visitor<'de> for ChildrenVisitor {
+                type Value = ConstraintChildren;
+
+                fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                    write!(f, "a constraint with child constraints")
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                    let mut ret = ConstraintChildren::default();
+                    let mut attributes: Vec<(String, String)> = vec![];
+                    while let Some(key) = map.next_key::<String>()? {
+                        match key.as_str() {
+                            "$value" => ret.constraints.push(map.next_value()?),
+                            "list" => ret.list = map.next_value()?,
+                            "@circular" => ret.circular = map.next_value()?,
+                            _ if key.starts_with('@') => attributes.push((key, map.next_value()?)),
+                            _ => {
+                                map.next_value::<IgnoredAny>()?;
+                            }
+                        }
+                    }
+                    ret.attributes = ConstraintAttributes::deserialize(MapDeserializer::new(
+                        attributes.into_iter(),
+                    ))?;
+                    Ok(ret)
+                }
+            }
+
+            deserializer.deserialize_struct(
+                "ConstraintChildren",
+                &["list", "$value"],
+                ChildrenVisitor,
+            )
+        }
+    }
 }
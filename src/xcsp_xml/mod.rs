@@ -47,11 +47,13 @@
 pub mod xcsp_xml_model;
 
 //private mod
+mod annotation;
 mod constraint;
 mod constraint_block;
 mod constraint_group;
 mod constraint_type;
 mod objective;
+mod quantification;
 mod variable;
 mod variable_array;
 mod variable_domain;
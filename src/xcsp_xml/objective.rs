@@ -87,6 +87,14 @@ pub mod xcsp3_xml {
         pub expression: String,
     }
 
+    #[derive(Deserialize, Debug)]
+    pub enum ObjectiveType {
+        #[serde(rename = "minimize")]
+        Minimize(MaximizeMinimize),
+        #[serde(rename = "maximize")]
+        Maximize(MaximizeMinimize),
+    }
+
     /**
     ```xml
     <objectives  [ combination="combinationType" ]>
@@ -102,9 +110,10 @@ pub mod xcsp3_xml {
     */
     #[derive(Deserialize, Debug)]
     pub struct Objective {
-        #[serde(rename = "minimize", default)]
-        pub minimize: Vec<MaximizeMinimize>,
-        #[serde(rename = "maximize", default)]
-        pub maximize: Vec<MaximizeMinimize>,
+        #[serde(rename = "@combination", default)]
+        pub combination: String,
+        /// minimize and maximize in the order of declaration
+        #[serde(rename = "$value", default)]
+        pub objectives: Vec<ObjectiveType>,
     }
 }
@@ -0,0 +1,77 @@
+/*=============================================================================
+* parser for CSP instances represented in XCSP3 Format
+*
+* Copyright (c) 2023 xcsp.org (contact @ xcsp.org)
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to deal
+* in the Software without restriction, including without limitation the rights
+* to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+* copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in
+* all copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*=============================================================================
+*/
+
+/*
+ * <p>@project_name: xcsp3-rust
+ * </p>
+ * <p>@author: luhan zhen
+ * </p>
+ * <p>@date:  2026/10/18 10:12
+ * </p>
+ * <p>@email: zhenlh20@mails.jlu.edu.cn
+ * </p>
+ * <p>@version: 1.0
+ * </p>
+ * <p>@description:
+ * </p>
+ */
+
+pub mod xcsp3_xml {
+    use serde::Deserialize;
+
+    /**
+    ```xml
+    <exists> (intVar wspace)+ </exists>
+    <forall> (intVar wspace)+ </forall>
+    ```
+     */
+    #[derive(Deserialize, Debug)]
+    pub enum QuantifierBlock {
+        #[serde(rename = "exists")]
+        Exists {
+            #[serde(rename = "$value", default)]
+            list: String,
+        },
+        #[serde(rename = "forall")]
+        Forall {
+            #[serde(rename = "$value", default)]
+            list: String,
+        },
+    }
+
+    /**
+    the quantification of QCSP and QCOP instances
+    ```xml
+    <quantification>
+      ( <exists.../> | <forall.../> )+
+    </quantification>
+    ```
+     */
+    #[derive(Deserialize, Debug)]
+    pub struct Quantification {
+        #[serde(rename = "$value", default)]
+        pub blocks: Vec<QuantifierBlock>,
+    }
+}
@@ -98,5 +98,9 @@ pub mod xcsp3_xml {
         pub value: String,
         #[serde(rename = "domain", default)]
         pub domains: Vec<VariableDomain>,
+        #[serde(rename = "required", default)]
+        pub required: String,
+        #[serde(rename = "possible", default)]
+        pub possible: String,
     }
 }
@@ -55,6 +55,14 @@ pub mod xcsp3_xml {
     <var id="bar"> 0..6 </var>
     <var id="qux"> -6..-2 0 1..3 4 7 8..11 </var>
     ```
+
+    a set variable is given by its required and possible values.
+    ```xml
+    <var id="s" type="set">
+      <required> 1 2 </required>
+      <possible> 1..5 </possible>
+    </var>
+    ```
     */
     #[derive(Deserialize, Debug)]
     pub struct VariableVar {
@@ -66,5 +74,9 @@ pub mod xcsp3_xml {
         pub value: String,
         #[serde(rename = "@as", default)]
         pub r#as: String,
+        #[serde(rename = "required", default)]
+        pub required: String,
+        #[serde(rename = "possible", default)]
+        pub possible: String,
     }
 }
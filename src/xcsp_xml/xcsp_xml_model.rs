@@ -41,12 +41,13 @@
 /**
 the SYNTAX of xcsp3 is as follows:
 ```xml
-<instance format="XCSP<sup>3</sup>" type="frameworkType">
+<instance format="XCSP<sup>3</sup>" type="frameworkType" [ lb="integer" ] [ ub="integer" ]>
   <variables>
     ( <var .../>
     | <array .../>
     )+
   </variables>
+  [<quantification .../>]
   <constraints>
     ( <constraint .../>
     | <metaConstraint .../>
@@ -64,26 +65,74 @@ the SYNTAX of xcsp3 is as follows:
 ```
  */
 pub mod xcsp3_xml {
+    use crate::annotations::xannotations::xcsp3_core::{XAnnotations, XHeuristicPart};
     use crate::constraints::xconstraint_set::xcsp3_core::XConstraintSet;
+    use crate::constraints::xconstraint_type::xcsp3_core::XConstraintType;
+    use crate::constraints::xmeta_constraint::xcsp3_core::{XMetaConstraint, XMetaType};
+    use crate::errors::xcsp3error::xcsp3_core::Xcsp3Error;
+    use crate::frameworks::xcost_bounds::xcsp3_core::XCostBounds;
+    use crate::frameworks::xquantification::xcsp3_core::{XQuantification, XQuantifierType};
     use crate::objectives::xobjectives_set::xcsp3_core::XObjectivesSet;
     use crate::variables::xvariable_set::xcsp3_core::XVariableSet;
+    use crate::xcsp_xml::annotation::xcsp3_xml::{Annotations, Heuristic, HeuristicPart};
     use crate::xcsp_xml::constraint::xcsp3_xml::Constraint;
     use crate::xcsp_xml::constraint_type::xcsp3_xml::ConstraintType;
-    use crate::xcsp_xml::objective::xcsp3_xml::Objective;
+    use crate::xcsp_xml::objective::xcsp3_xml::{Objective, ObjectiveType};
+    use crate::xcsp_xml::quantification::xcsp3_xml::{Quantification, QuantifierBlock};
     use crate::xcsp_xml::variable::xcsp3_xml::Variable;
     use crate::xcsp_xml::variable_type::xcsp3_xml::VariableType;
     use quick_xml::de::from_str;
     use quick_xml::DeError;
+    use regex::Regex;
     use serde::Deserialize;
     use std::fs;
     use std::time::Instant;
 
-    #[derive(Deserialize, Debug)]
+    /// the framework types of XCSP3
+    #[derive(Deserialize, Debug, Clone, PartialEq)]
     pub enum InstanceType {
         #[serde(rename = "CSP")]
         Csp,
         #[serde(rename = "COP")]
         Cop,
+        /// weighted CSP, see the attributes lb and ub of instance
+        #[serde(rename = "WCSP")]
+        Wcsp,
+        #[serde(rename = "Max-CSP", alias = "MaxCSP")]
+        MaxCsp,
+        /// fuzzy CSP
+        #[serde(rename = "FCSP")]
+        Fcsp,
+        /// quantified CSP, see the quantification of instance
+        #[serde(rename = "QCSP")]
+        Qcsp,
+        #[serde(rename = "QCSP+")]
+        QcspPlus,
+        #[serde(rename = "QCOP")]
+        Qcop,
+        #[serde(rename = "QCOP+")]
+        QcopPlus,
+        /// stochastic CSP
+        #[serde(rename = "SCSP")]
+        Scsp,
+        #[serde(rename = "SCOP")]
+        Scop,
+        /// qualitative spatial temporal reasoning
+        #[serde(rename = "QSTR")]
+        Qstr,
+        /// temporal CSP
+        #[serde(rename = "TCSP")]
+        Tcsp,
+        /// continuous CSP
+        #[serde(rename = "NCSP")]
+        Ncsp,
+        #[serde(rename = "NCOP")]
+        Ncop,
+        /// distributed CSP
+        #[serde(rename = "DisCSP")]
+        DisCsp,
+        #[serde(rename = "DisWCSP")]
+        DisWcsp,
     }
 
     /// the instance of XCSP3
@@ -93,9 +142,15 @@ pub mod xcsp3_xml {
         format: String,
         #[serde(rename = "@type")]
         r#type: InstanceType,
+        #[serde(rename = "@lb", default)]
+        lb: String,
+        #[serde(rename = "@ub", default)]
+        ub: String,
         variables: Option<Variable>,
+        quantification: Option<Quantification>,
         constraints: Constraint,
         objectives: Option<Objective>,
+        annotations: Option<Annotations>,
     }
 
     impl XcspXmlModel {
@@ -103,17 +158,105 @@ pub mod xcsp3_xml {
             let mut object = XObjectivesSet::new(set);
             // println!("{:?}", self.objectives);
             if let Some(oo) = &self.objectives {
-                for e in oo.maximize.iter() {
-                    object.build_maximize(&e.list, &e.coeffs, &e.expression, &e.r#type.to_string())
-                }
-                for e in oo.minimize.iter() {
-                    object.build_minimize(&e.list, &e.coeffs, &e.expression, &e.r#type.to_string())
+                object.build_combination(&oo.combination);
+                for o in oo.objectives.iter() {
+                    match o {
+                        ObjectiveType::Maximize(e) => object.build_maximize(
+                            &e.list,
+                            &e.coeffs,
+                            &e.expression,
+                            &e.r#type.to_string(),
+                        ),
+                        ObjectiveType::Minimize(e) => object.build_minimize(
+                            &e.list,
+                            &e.coeffs,
+                            &e.expression,
+                            &e.r#type.to_string(),
+                        ),
+                    }
                 }
             }
 
             object
         }
 
+        /// build the annotations, the lists of variables are resolved by the set of variables
+        pub fn build_annotations<'a>(
+            &'a self,
+            set: &'a XVariableSet,
+        ) -> Result<XAnnotations<'a>, Xcsp3Error> {
+            let mut annotations = XAnnotations::new(set);
+            if let Some(aa) = &self.annotations {
+                annotations.build_decision(&aa.decision)?;
+                annotations.build_output(&aa.output)?;
+                if let Some(h) = &aa.var_heuristic {
+                    let parts = XcspXmlModel::build_heuristic_parts(&annotations, h)?;
+                    annotations.build_var_heuristic(&h.lc, parts)?;
+                }
+                if let Some(h) = &aa.val_heuristic {
+                    let parts = XcspXmlModel::build_heuristic_parts(&annotations, h)?;
+                    annotations.build_val_heuristic(parts);
+                }
+                for e in aa.filtering.iter() {
+                    annotations.build_filtering(&e.r#type, &e.constraints);
+                }
+                for e in aa.prepro.iter() {
+                    annotations.build_prepro(&e.consistency, &e.constraints);
+                }
+            }
+            Ok(annotations)
+        }
+
+        /// build the quantification of QCSP and QCOP instances, it is empty for the other frameworks
+        pub fn build_quantification<'a>(
+            &'a self,
+            set: &'a XVariableSet,
+        ) -> Result<XQuantification<'a>, Xcsp3Error> {
+            let mut quantification = XQuantification::new(set);
+            if let Some(q) = &self.quantification {
+                for b in q.blocks.iter() {
+                    match b {
+                        QuantifierBlock::Exists { list } => {
+                            quantification.build_block(XQuantifierType::Exists, list)?
+                        }
+                        QuantifierBlock::Forall { list } => {
+                            quantification.build_block(XQuantifierType::Forall, list)?
+                        }
+                    }
+                }
+            }
+            Ok(quantification)
+        }
+
+        /// get the bounds of the costs of WCSP instances
+        pub fn get_cost_bounds(&self) -> Result<XCostBounds, Xcsp3Error> {
+            XCostBounds::from_str(&self.lb, &self.ub)
+        }
+
+        fn build_heuristic_parts(
+            annotations: &XAnnotations,
+            heuristic: &Heuristic,
+        ) -> Result<Vec<XHeuristicPart>, Xcsp3Error> {
+            let mut parts = vec![];
+            for p in heuristic.parts.iter() {
+                parts.push(match p {
+                    HeuristicPart::Static { order, list } => {
+                        annotations.build_heuristic_part("static", "", order, list)?
+                    }
+                    HeuristicPart::Random { list } => {
+                        annotations.build_heuristic_part("random", "", "", list)?
+                    }
+                    HeuristicPart::Min { r#type, list } => {
+                        annotations.build_heuristic_part("min", r#type, "", list)?
+                    }
+                    HeuristicPart::Max { r#type, list } => {
+                        annotations.build_heuristic_part("max", r#type, "", list)?
+                    }
+                });
+            }
+            Ok(parts)
+        }
+
         /// read the instance from the xml file
         pub fn from_path(path: &str) -> Result<XcspXmlModel, DeError> {
             let _now = Instant::now();
@@ -142,7 +285,7 @@ pub mod xcsp3_xml {
             self.format.clone()
         }
 
-        /// get the type of the instance:  COP or CSP
+        /// get the type of the instance, such as CSP, COP, WCSP or QCSP
         pub fn get_instance_type(&self) -> &InstanceType {
             &self.r#type
         }
@@ -154,7 +297,15 @@ pub mod xcsp3_xml {
             for var_type in self.variables.as_ref().unwrap().variables.iter() {
                 match var_type {
                     VariableType::Var(var_string) => {
-                        if var_string.r#as.is_empty() {
+                        if var_string.r#type.eq("set") {
+                            variables.build_variable_set(
+                                &var_string.id,
+                                &var_string.required,
+                                &var_string.possible,
+                            );
+                        } else if var_string.r#type.eq("real") {
+                            variables.build_variable_real(&var_string.id, &var_string.value);
+                        } else if var_string.r#as.is_empty() {
                             variables.build_variable_int(
                                 &var_string.id,
                                 &var_string.value,
@@ -166,11 +317,28 @@ pub mod xcsp3_xml {
                     }
                     VariableType::Array(var_array_str) => {
                         // println!("var_array {:?}", var_array)
-                        if var_array_str.domains.is_empty() {
+                        if var_array_str.type_.eq("set") {
+                            variables.build_variable_set_array(
+                                &var_array_str.id,
+                                &var_array_str.size,
+                                &var_array_str.required,
+                                &var_array_str.possible,
+                                &var_array_str.start_index,
+                            );
+                        } else if var_array_str.type_.eq("real") {
+                            variables.build_variable_real_array(
+                                &var_array_str.id,
+                                &var_array_str.size,
+                                &var_array_str.value,
+                                &var_array_str.start_index,
+                            );
+                        } else if var_array_str.domains.is_empty() {
                             variables.build_variable_array(
                                 &var_array_str.id,
                                 &var_array_str.size,
                                 &var_array_str.value,
+                                &var_array_str.type_,
+                                &var_array_str.start_index,
                             );
                         } else {
                             let mut domain_for: Vec<&String> = vec![];
@@ -184,6 +352,7 @@ pub mod xcsp3_xml {
                                 &var_array_str.size,
                                 domain_for,
                                 domain_value,
+                                &var_array_str.start_index,
                             );
                         }
                     }
@@ -192,17 +361,131 @@ pub mod xcsp3_xml {
             variables
         }
 
+        /// replace %i and %... in the template by the arguments, %... stands for the arguments after the last %i
+        fn instantiate_template(template: &str, args: &str) -> String {
+            let args: Vec<&str> = args.split_whitespace().collect();
+            let re = Regex::new(r"%(0|[1-9][0-9]*)").unwrap();
+            let rest = re
+                .captures_iter(template)
+                .filter_map(|c| c[1].parse::<usize>().ok())
+                .map(|n| n + 1)
+                .max()
+                .unwrap_or(0);
+            let mut ret = String::default();
+            for e in template.split_whitespace() {
+                if e.contains("%...") {
+                    for a in args.iter().skip(rest) {
+                        ret.push_str(&e.replace("%...", a));
+                        ret.push(' ');
+                    }
+                } else {
+                    ret.push_str(&re.replace_all(e, |c: &regex::Captures| {
+                        match c[1].parse::<usize>().ok().and_then(|n| args.get(n)) {
+                            Some(a) => a.to_string(),
+                            None => c[0].to_string(),
+                        }
+                    }));
+                    ret.push(' ');
+                }
+            }
+            ret
+        }
+
+        /// the children are built by parse_constraint, then taken back from the set
+        fn parse_meta_constraint(
+            meta_type: XMetaType,
+            constraints: &[ConstraintType],
+            set: &mut XConstraintSet,
+        ) {
+            let mut children = vec![];
+            for c in constraints.iter() {
+                // a child building several constraints is one child, the conjunction of them
+                let start = set.iter().len();
+                XcspXmlModel::parse_constraint(c, set);
+                let mut built = set.drain_from(start);
+                match built.len() {
+                    0 => children.push(XConstraintType::XConstraintNone(
+                        Xcsp3Error::get_constraint_meta_error(&format!(
+                            "parse {:?} meta-constraint error, a child builds no constraint, ",
+                            meta_type
+                        )),
+                    )),
+                    1 => children.push(built.pop().unwrap()),
+                    _ => children.push(
+                        match XMetaConstraint::from_children(XMetaType::And, built) {
+                            Ok(c) => XConstraintType::XMetaConstraint(c),
+                            Err(e) => XConstraintType::XConstraintNone(e),
+                        },
+                    ),
+                }
+            }
+            set.build_meta_constraint(meta_type, children);
+        }
+
+        /// the group templates which are unrolled into one constraint per arguments, not(x) can not be
+        /// an argument of XGroup and the symbols are mapped by the domains of the substituted variables
+        fn is_unrolled(template: &ConstraintType) -> bool {
+            let has_symbols = |s: &str| s.contains(|c: char| c.is_ascii_alphabetic());
+            match template {
+                ConstraintType::Clause { .. } => true,
+                ConstraintType::Extension {
+                    attributes,
+                    supports,
+                    conflicts,
+                    ..
+                } => !attributes.is_soft() && (has_symbols(supports) || has_symbols(conflicts)),
+                ConstraintType::Instantiation { values, .. } => has_symbols(values),
+                _ => false,
+            }
+        }
+
+        fn parse_unrolled(template: &ConstraintType, args: &str, set: &mut XConstraintSet) {
+            match template {
+                ConstraintType::Clause { vars, list, .. } => {
+                    let vars = if !vars.trim().is_empty() { vars } else { list };
+                    set.build_clause(&XcspXmlModel::instantiate_template(vars, args));
+                }
+                ConstraintType::Extension {
+                    vars,
+                    supports,
+                    conflicts,
+                    ..
+                } => {
+                    let vars = XcspXmlModel::instantiate_template(vars, args);
+                    if supports.is_empty() {
+                        set.build_extension(&vars, conflicts, false)
+                    } else {
+                        set.build_extension(&vars, supports, true)
+                    }
+                }
+                ConstraintType::Instantiation { vars, values, .. } => {
+                    set.build_instantiation(
+                        &XcspXmlModel::instantiate_template(vars, args),
+                        values,
+                    );
+                }
+                _ => {}
+            }
+        }
+
         fn parse_constraint(con_type: &ConstraintType, set: &mut XConstraintSet) {
+            let start = set.iter().len();
             match con_type {
                 ConstraintType::Group(group) => {
                     // println!("{:?}", group.constraints);
-                    XcspXmlModel::parse_constraint(&group.constraints, set);
+                    if XcspXmlModel::is_unrolled(&group.constraints) {
+                        for args in group.args.iter() {
+                            XcspXmlModel::parse_unrolled(&group.constraints, args, set);
+                        }
+                    } else {
+                        XcspXmlModel::parse_constraint(&group.constraints, set);
 
-                    match set.get_last_constraint() {
-                        None => {}
-                        Some(cc) => {
-                            // println!("{}",cc.to_string())
-                            set.build_group(cc, &group.args);
+                        match set.get_last_constraint() {
+                            None => {}
+                            Some(cc) => {
+                                // println!("{}",cc.to_string())
+                                set.build_group(cc, &group.args);
+                            }
                         }
                     }
                 }
@@ -217,6 +500,7 @@ pub mod xcsp3_xml {
                     list,
                     except,
                     matrix,
+                    ..
                 } => {
                     if !vars.is_empty() {
                         set.build_all_different(vars)
@@ -234,7 +518,7 @@ pub mod xcsp3_xml {
                         set.build_all_different_matrix(matrix);
                     }
                 }
-                ConstraintType::AllEqual { vars, list } => {
+                ConstraintType::AllEqual { vars, list, .. } => {
                     if !vars.is_empty() {
                         set.build_all_equal(vars);
                     } else {
@@ -243,13 +527,24 @@ pub mod xcsp3_xml {
                         }
                     }
                 }
-                ConstraintType::Circuit { .. } => {}
+                ConstraintType::Circuit {
+                    vars, list, size, ..
+                } => {
+                    if !vars.is_empty() {
+                        set.build_circuit(vars, "", size);
+                    } else {
+                        set.build_circuit(&list.value, &list.start_index, size);
+                    }
+                }
                 ConstraintType::Ordered {
                     vars,
                     operator,
                     lengths,
+                    ..
                 } => set.build_ordered(vars, lengths, operator),
-                ConstraintType::Intension { value, function } => {
+                ConstraintType::Intension {
+                    value, function, ..
+                } => {
                     if !value.is_empty() {
                         set.build_intention(value);
                     } else if !function.is_empty() {
@@ -260,8 +555,15 @@ pub mod xcsp3_xml {
                     vars,
                     supports,
                     conflicts,
+                    tuples,
+                    attributes,
+                    default_cost,
+                    ..
                 } => {
-                    if supports.is_empty() {
+                    if attributes.is_soft() {
+                        let tuples = if tuples.is_empty() { supports } else { tuples };
+                        set.build_soft_extension(vars, tuples, default_cost, &attributes.ub)
+                    } else if supports.is_empty() {
                         set.build_extension(vars, conflicts, false)
                     } else if conflicts.is_empty() {
                         set.build_extension(vars, supports, true)
@@ -274,45 +576,129 @@ pub mod xcsp3_xml {
                     transitions,
                     start,
                     r#final,
+                    ..
                 } => set.build_regular(vars, transitions, start, r#final),
-                ConstraintType::Mdd { vars, transitions } => set.build_mdd(vars, transitions),
+                ConstraintType::Mdd {
+                    vars, transitions, ..
+                } => set.build_mdd(vars, transitions),
                 ConstraintType::Sum {
                     vars,
                     condition,
                     coeffs,
+                    ..
                 } => set.build_sum(vars, condition, coeffs),
                 ConstraintType::Count {
                     vars,
                     values,
                     condition,
+                    ..
                 } => set.build_count(vars, condition, values),
 
                 ConstraintType::NValues {
                     vars,
                     except,
                     condition,
+                    ..
                 } => set.build_n_values(vars, condition, except),
                 ConstraintType::Cardinality {
                     list,
                     values,
                     occurs,
+                    ..
                 } => set.build_cardinality(list, &values.vars, occurs, &values.closed),
-                ConstraintType::Minimum { list, condition } => set.build_minimum(list, condition),
-                ConstraintType::Maximum { list, condition } => set.build_maximum(list, condition),
-                ConstraintType::Element { vars, value, index } => {
-                    set.build_element(&vars.value, value, index, &vars.start_index)
+                ConstraintType::Precedence { list, values, .. } => {
+                    set.build_precedence(list, &values.vars, &values.covered)
+                }
+                ConstraintType::BinPacking {
+                    list,
+                    sizes,
+                    condition,
+                    limits,
+                    loads,
+                    ..
+                } => set.build_bin_packing(list, sizes, condition, limits, loads),
+                ConstraintType::Clause { vars, list, .. } => {
+                    if !vars.trim().is_empty() {
+                        set.build_clause(vars);
+                    } else {
+                        set.build_clause(list);
+                    }
+                }
+                ConstraintType::AllDistant {
+                    list, condition, ..
+                } => set.build_all_distant(list, condition),
+                ConstraintType::Balance {
+                    list,
+                    values,
+                    condition,
+                    ..
+                } => set.build_balance(list, values, condition),
+                ConstraintType::Spread {
+                    list,
+                    total,
+                    condition,
+                    ..
+                } => set.build_spread(list, total, condition),
+                ConstraintType::Deviation {
+                    list,
+                    total,
+                    condition,
+                    ..
+                } => set.build_deviation(list, total, condition),
+                ConstraintType::Knapsack {
+                    list,
+                    weights,
+                    profits,
+                    conditions,
+                    ..
+                } => set.build_knapsack(list, weights, profits, conditions),
+                ConstraintType::Flow {
+                    list,
+                    balance,
+                    arcs,
+                    weights,
+                    condition,
+                    ..
+                } => set.build_flow(list, balance, arcs, weights, condition),
+                ConstraintType::And(meta) => {
+                    XcspXmlModel::parse_meta_constraint(XMetaType::And, &meta.constraints, set)
+                }
+                ConstraintType::Or(meta) => {
+                    XcspXmlModel::parse_meta_constraint(XMetaType::Or, &meta.constraints, set)
+                }
+                ConstraintType::Not(meta) => {
+                    XcspXmlModel::parse_meta_constraint(XMetaType::Not, &meta.constraints, set)
                 }
+                ConstraintType::IfThen(meta) => {
+                    XcspXmlModel::parse_meta_constraint(XMetaType::IfThen, &meta.constraints, set)
+                }
+                ConstraintType::IfThenElse(meta) => XcspXmlModel::parse_meta_constraint(
+                    XMetaType::IfThenElse,
+                    &meta.constraints,
+                    set,
+                ),
+                ConstraintType::Minimum {
+                    list, condition, ..
+                } => set.build_minimum(list, condition),
+                ConstraintType::Maximum {
+                    list, condition, ..
+                } => set.build_maximum(list, condition),
+                ConstraintType::Element {
+                    vars, value, index, ..
+                } => set.build_element(&vars.value, value, index, &vars.start_index),
 
                 ConstraintType::Stretch {
                     vars,
                     values,
                     widths,
                     patterns,
+                    ..
                 } => set.build_stretch(vars, values, widths, patterns),
                 ConstraintType::NoOverlap {
                     origins,
                     lengths,
                     zero_ignored,
+                    ..
                 } => {
                     if origins.contains(',') && origins.contains('(') {
                         set.build_no_overlap_k_dim(origins, lengths, zero_ignored);
@@ -327,6 +713,7 @@ pub mod xcsp3_xml {
                     condition,
                     ends,
                     machines,
+                    ..
                 } => set.build_cumulative(
                     origins,
                     lengths,
@@ -336,22 +723,25 @@ pub mod xcsp3_xml {
                     machines,
                     &condition.start_index,
                 ),
-                ConstraintType::Instantiation { vars, values } => {
+                ConstraintType::Instantiation { vars, values, .. } => {
                     // println!("{}{:?}", vars, values);
                     set.build_instantiation(vars, values);
                 }
-                ConstraintType::Slide {
-                    circular,
-                    list,
-                    constraints,
-                } => {
+                ConstraintType::Slide(slide) => {
                     // println!("{circular} {:?},{:?}", list, constraints);
-                    XcspXmlModel::parse_constraint(constraints, set);
-                    match set.get_last_constraint() {
-                        None => {}
-                        Some(cc) => {
-                            // println!("{}",cc.to_string())
-                            set.build_slide(cc, &list.vars, &list.offset, circular);
+                    if let Some(template) = slide.constraints.first() {
+                        XcspXmlModel::parse_constraint(template, set);
+                        match set.get_last_constraint() {
+                            None => {}
+                            Some(cc) => {
+                                // println!("{}",cc.to_string())
+                                set.build_slide(
+                                    cc,
+                                    &slide.list.vars,
+                                    &slide.list.offset,
+                                    &slide.circular,
+                                );
+                            }
                         }
                     }
                 }
@@ -359,6 +749,7 @@ pub mod xcsp3_xml {
                     lists,
                     with_value,
                     simplified_list,
+                    ..
                 } => {
                     if !simplified_list.is_empty() {
                         set.build_channel(simplified_list, "", "");
@@ -370,18 +761,37 @@ pub mod xcsp3_xml {
                         set.build_channel(&lists[0].value, &lists[0].start_index, with_value);
                     }
                 }
+                ConstraintType::Lex {
+                    lists,
+                    matrix,
+                    operator,
+                    ..
+                } => {
+                    if matrix.is_empty() {
+                        set.build_lex(lists, operator);
+                    } else {
+                        set.build_lex_matrix(matrix, operator);
+                    }
+                }
 
-                // ConstraintType::AllDistant { .. } => {}
-                // ConstraintType::Precedence { .. } => {}
-                // ConstraintType::Balance { .. } => {}
-                // ConstraintType::Spread { .. } => {}
-                // ConstraintType::Deviation { .. } => {}
-                // ConstraintType::BinPacking { .. } => {}
-                // ConstraintType::Lex { .. } => {}
-                // ConstraintType::Clause { .. } => {}
                 // _ => {}
                 ConstraintType::ConstraintNone => {}
             }
+            // the unrolled constraints of a group take the attributes of the template
+            let con_type = match con_type {
+                ConstraintType::Group(group) if XcspXmlModel::is_unrolled(&group.constraints) => {
+                    group.constraints.as_ref()
+                }
+                _ => con_type,
+            };
+            if let Some((measure, violation_cost, ub)) = con_type.get_soft() {
+                set.build_soft(start, measure, violation_cost, ub);
+            }
+            if let Some(reification) = con_type.get_reification() {
+                for c in set.iter_mut().skip(start) {
+                    c.set_reification(Some(reification.clone()));
+                }
+            }
         }
 
         pub fn build_constraints<'a>(&'a self, set: &'a XVariableSet) -> XConstraintSet<'a> {
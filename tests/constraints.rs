@@ -0,0 +1,518 @@
+use xcsp3_rust::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+use xcsp3_rust::constraints::xconstraint_type::xcsp3_core::XConstraintType;
+use xcsp3_rust::constraints::xsoft_constraint::xcsp3_core::XSoftCost;
+use xcsp3_rust::xcsp_xml::xcsp_xml_model::xcsp3_xml::XcspXmlModel;
+
+/*
+ * the behavior of the global constraints, the parsed fields and the parse errors
+ */
+
+fn model(variables: &str, constraints: &str) -> XcspXmlModel {
+    let xml = format!(
+        "<instance format=\"XCSP3\" type=\"CSP\"><variables>{}</variables>\
+         <constraints>{}</constraints></instance>",
+        variables, constraints
+    );
+    XcspXmlModel::from_string(&xml).unwrap()
+}
+
+const VARIABLES: &str = "<array id=\"x\" size=\"[4]\"> 0..3 </array> \
+    <var id=\"y\"> 0..5 </var> <var id=\"z\"> 0..5 </var>";
+
+/// the message of a constraint which is not built, None for a built constraint
+fn error(c: &XConstraintType) -> Option<String> {
+    match c {
+        XConstraintType::XConstraintNone(e) => Some(e.to_string()),
+        _ => None,
+    }
+}
+
+/// the shape of a meta-constraint, its type and the shapes of its children
+fn meta_shape(c: &XConstraintType) -> String {
+    match c {
+        XConstraintType::XMetaConstraint(m) => format!(
+            "{:?}({})",
+            m.get_meta_type(),
+            m.get_children()
+                .iter()
+                .map(meta_shape)
+                .collect::<Vec<String>>()
+                .join(",")
+        ),
+        XConstraintType::XConstraintNone(_) => "None".to_string(),
+        _ => "C".to_string(),
+    }
+}
+
+#[test]
+fn meta_constraints_take_one_child_per_element() {
+    let model = model(
+        VARIABLES,
+        "<or><intension> eq(x[0],x[1]) </intension><allDifferent><list> x[0] x[1] </list>\
+         <list> x[2] x[3] </list></allDifferent></or>\
+         <not><allDifferent><list> x[0] x[1] </list><list> x[2] x[3] </list></allDifferent></not>\
+         <ifThen><intension> eq(y,1) </intension><intension> eq(z,2) </intension></ifThen>\
+         <ifThenElse><intension> eq(y,1) </intension><intension> eq(z,2) </intension></ifThenElse>\
+         <not><intension> eq(y,1) </intension><intension> eq(z,2) </intension></not>",
+    );
+    let set = model.build_variables();
+    let shapes: Vec<String> = model
+        .build_constraints(&set)
+        .iter()
+        .map(meta_shape)
+        .collect();
+    assert_eq!(
+        shapes,
+        vec![
+            "Or(C,And(C,C))",
+            "Not(And(C,C))",
+            "IfThen(C,C)",
+            // an ifThenElse needs three children
+            "None",
+            "None",
+        ]
+    );
+}
+
+#[test]
+fn knapsack_and_flow_check_their_lengths() {
+    let model = model(
+        VARIABLES,
+        "<knapsack><list> x[] </list><weights> 5 3 2 4 </weights><profits> 2 1 3 4 </profits>\
+         <condition> (le,10) </condition><condition> (ge,y) </condition></knapsack>\
+         <knapsack><list> x[0..2] </list><weights> 5 3 2 4 </weights><profits> 2 1 3 4 </profits>\
+         <condition> (le,10) </condition><condition> (ge,y) </condition></knapsack>\
+         <knapsack><list> x[] </list><weights> 5 3 2 4 </weights><profits> 2 1 3 </profits>\
+         <condition> (le,10) </condition><condition> (ge,y) </condition></knapsack>\
+         <flow><list> x[] </list><balance> 3 0 -3 </balance><arcs> (1,2)(1,3)(2,3)(3,1) </arcs>\
+         <weights> 1 2 1 4 </weights><condition> (le,z) </condition></flow>\
+         <flow><list> x[] </list><balance> 3 0 -3 </balance><arcs> (1,2)(1,3)(2,3) </arcs></flow>\
+         <flow><list> x[0..2] </list><balance> 3 0 -3 </balance><arcs> (1,2)(1,3)(2,3) </arcs>\
+         <weights> 1 2 </weights><condition> (le,z) </condition></flow>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    match constraints[0] {
+        XConstraintType::XKnapsack(k) => {
+            assert_eq!(k.get_weights(), &vec![5, 3, 2, 4]);
+            assert_eq!(k.get_profits(), &vec![2, 1, 3, 4]);
+        }
+        c => panic!("{}", c),
+    }
+    assert!(error(constraints[1]).unwrap().contains("the size of list"));
+    assert!(error(constraints[2])
+        .unwrap()
+        .contains("the number of profits"));
+    match constraints[3] {
+        XConstraintType::XFlow(f) => {
+            assert_eq!(f.get_arcs(), &vec![(1, 2), (1, 3), (2, 3), (3, 1)]);
+            assert_eq!(f.get_weights(), &Some(vec![1, 2, 1, 4]));
+        }
+        c => panic!("{}", c),
+    }
+    // one variable per arc
+    assert!(error(constraints[4])
+        .unwrap()
+        .contains("the number of arcs"));
+    assert!(error(constraints[5])
+        .unwrap()
+        .contains("the number of weights"));
+}
+
+#[test]
+fn precedence_checks_its_list_and_values() {
+    let model = model(
+        VARIABLES,
+        "<precedence><list> x[] </list><values covered=\"true\"> 0 2 1 </values></precedence>\
+         <precedence> x[] </precedence>\
+         <precedence><list> w[] </list></precedence>\
+         <precedence><list> y </list></precedence>\
+         <precedence><list> x[] </list><values covered=\"true\"></values></precedence>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    match constraints[0] {
+        XConstraintType::XPrecedence(p) => {
+            assert_eq!(p.get_covered(), &Some(true));
+            assert_eq!(p.get_values().as_ref().map(|v| v.len()), Some(3));
+        }
+        c => panic!("{}", c),
+    }
+    // no list, an unknown array and a list of one variable
+    assert!(error(constraints[1]).is_some());
+    assert!(error(constraints[2]).is_some());
+    assert!(error(constraints[3])
+        .unwrap()
+        .contains("at least two variables"));
+    assert!(error(constraints[4])
+        .unwrap()
+        .contains("covered requires values"));
+}
+
+#[test]
+fn reification_attributes_are_read_on_every_constraint() {
+    let model = model(
+        "<array id=\"x\" size=\"[4]\"> 0..3 </array> <array id=\"b\" size=\"[5]\"> 0 1 </array>",
+        "<allDifferent reifiedBy=\"b[0]\"> x[] </allDifferent>\
+         <intension hreifiedFrom=\"b[1]\"> eq(x[0],x[1]) </intension>\
+         <or hreifiedTo=\"b[2]\"><intension> eq(x[0],1) </intension><intension> eq(x[1],1) </intension></or>\
+         <slide reifiedBy=\"b[3]\"><list> x[] </list><intension> lt(%0,%1) </intension></slide>\
+         <knapsack hreifiedTo=\"b[4]\"><list> x[] </list><weights> 1 1 1 1 </weights><profits> 1 1 1 1 </profits>\
+         <condition> (le,3) </condition><condition> (ge,1) </condition></knapsack>\
+         <allEqual> x[] </allEqual>",
+    );
+    let set = model.build_variables();
+    let reifications: Vec<Option<String>> = model
+        .build_constraints(&set)
+        .iter()
+        .map(|c| c.reification().map(|r| r.to_string()))
+        .collect();
+    assert_eq!(
+        reifications,
+        vec![
+            Some("ReifiedBy(b[0])".to_string()),
+            Some("HReifiedFrom(b[1])".to_string()),
+            Some("HReifiedTo(b[2])".to_string()),
+            Some("ReifiedBy(b[3])".to_string()),
+            Some("HReifiedTo(b[4])".to_string()),
+            None,
+        ]
+    );
+}
+
+/// the items of a list of variables and values as strings
+fn strings<T: ToString>(list: &[T]) -> Vec<String> {
+    list.iter().map(|e| e.to_string()).collect()
+}
+
+#[test]
+fn bin_packing_keeps_the_list_as_its_scope() {
+    let model = model(
+        "<array id=\"x\" size=\"[4]\"> 0..2 </array> <array id=\"l\" size=\"[3]\"> 0..9 </array> \
+         <var id=\"c\"> 0..9 </var>",
+        "<binPacking><list> x[] </list><sizes> 2 1 3 2 </sizes><condition> (le,c) </condition></binPacking>\
+         <binPacking><list> x[] </list><sizes> 2 1 3 2 </sizes><limits> 5 4 6 </limits></binPacking>\
+         <binPacking><list> x[] </list><sizes> 2 1 3 2 </sizes><loads> l[] </loads></binPacking>\
+         <binPacking><list> x[] </list><sizes> 2 1 3 2 </sizes></binPacking>\
+         <binPacking><list> x[] </list><sizes> 2 1 3 2 </sizes><limits> 5 4 6 </limits><loads> l[] </loads></binPacking>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    for c in constraints.iter().take(3) {
+        match c {
+            XConstraintType::XBinPacking(b) => {
+                assert_eq!(strings(b.get_scope_string()), vec!["x[]"]);
+                assert_eq!(strings(b.get_sizes()), vec!["2", "1", "3", "2"]);
+            }
+            c => panic!("{}", c),
+        }
+    }
+    match constraints[0] {
+        XConstraintType::XBinPacking(b) => {
+            assert_eq!(format!("{:?}", b.get_operator()), "Some(Le)");
+            assert_eq!(format!("{:?}", b.get_operand()), "Some(Variable(\"c\"))");
+        }
+        c => panic!("{}", c),
+    }
+    match constraints[1] {
+        XConstraintType::XBinPacking(b) => {
+            assert_eq!(
+                b.get_limits().as_ref().map(|l| strings(l)),
+                Some(vec!["5".to_string(), "4".to_string(), "6".to_string()])
+            )
+        }
+        c => panic!("{}", c),
+    }
+    match constraints[2] {
+        XConstraintType::XBinPacking(b) => {
+            assert_eq!(
+                b.get_loads().as_ref().map(|l| strings(l)),
+                Some(vec!["l[]".to_string()])
+            )
+        }
+        c => panic!("{}", c),
+    }
+    // exactly one of condition, limits and loads
+    assert!(error(constraints[3]).unwrap().contains("exactly one"));
+    assert!(error(constraints[4]).unwrap().contains("exactly one"));
+}
+
+#[test]
+fn soft_constraints_keep_their_costs() {
+    let model = model(
+        VARIABLES,
+        "<extension type=\"soft\" defaultCost=\"5\" ub=\"10\"><list> y z </list>\
+         <tuples> (0,1):2 (1,*):0 </tuples></extension>\
+         <intension type=\"soft\" violationCost=\"3\"> eq(y,z) </intension>\
+         <allDifferent type=\"soft\" violationMeasure=\"var\"> x[] </allDifferent>\
+         <intension type=\"soft\" violationCost=\"high\"> eq(y,z) </intension>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    match constraints[0] {
+        XConstraintType::XSoftConstraint(s) => {
+            assert_eq!(
+                s.get_cost(),
+                &XSoftCost::Tuples {
+                    default_cost: 5,
+                    tuples: vec![vec![Some(0), Some(1)], vec![Some(1), None]],
+                    costs: vec![2, 0],
+                }
+            );
+            assert_eq!(s.get_ub(), &Some(10));
+            // the wrapped extension is not a table of supports
+            match s.get_constraint() {
+                XConstraintType::XExtension(e) => {
+                    assert!(e.supports_iter().is_none());
+                    assert_eq!(e.conflicts_iter().map(|t| t.count()), Some(0));
+                    assert_eq!(strings(e.get_scope_string()), vec!["y", "z"]);
+                }
+                c => panic!("{}", c),
+            }
+        }
+        c => panic!("{}", c),
+    }
+    match constraints[1] {
+        XConstraintType::XSoftConstraint(s) => {
+            assert_eq!(
+                s.get_cost(),
+                &XSoftCost::Violation {
+                    measure: None,
+                    violation_cost: Some(3),
+                }
+            );
+            assert!(matches!(s.get_constraint(), XConstraintType::XIntention(_)));
+        }
+        c => panic!("{}", c),
+    }
+    match constraints[2] {
+        XConstraintType::XSoftConstraint(s) => assert_eq!(
+            s.get_cost(),
+            &XSoftCost::Violation {
+                measure: Some("var".to_string()),
+                violation_cost: None,
+            }
+        ),
+        c => panic!("{}", c),
+    }
+    assert!(error(constraints[3]).is_some());
+}
+
+#[test]
+fn circuit_is_built_with_its_start_index_and_size() {
+    let model = model(
+        VARIABLES,
+        "<circuit> x[] </circuit>\
+         <circuit><list startIndex=\"1\"> x[] </list><size> 2 </size></circuit>\
+         <circuit><list> x[] </list><size> y </size></circuit>\
+         <circuit><list startIndex=\"one\"> x[] </list></circuit>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    let circuit = |c: &XConstraintType| match c {
+        XConstraintType::XCircuit(c) => (
+            strings(c.get_scope_string()),
+            *c.get_start_index(),
+            c.get_size().as_ref().map(|s| s.to_string()),
+        ),
+        c => panic!("{}", c),
+    };
+    assert_eq!(
+        circuit(constraints[0]),
+        (vec!["x[]".to_string()], None, None)
+    );
+    assert_eq!(
+        circuit(constraints[1]),
+        (vec!["x[]".to_string()], Some(1), Some("2".to_string()))
+    );
+    assert_eq!(
+        circuit(constraints[2]),
+        (vec!["x[]".to_string()], None, Some("y".to_string()))
+    );
+    assert!(error(constraints[3]).unwrap().contains("start_index"));
+}
+
+#[test]
+fn lex_compares_lists_of_the_same_length() {
+    let model = model(
+        "<array id=\"x\" size=\"[3]\"> 0..3 </array> <array id=\"y\" size=\"[3]\"> 0..3 </array> \
+         <array id=\"m\" size=\"[2][3]\"> 0..3 </array> <var id=\"z\"> 0..3 </var>",
+        "<lex><list> x[] </list><list> y[] </list><operator> lt </operator></lex>\
+         <lex><list> x[] </list><list> y[0] y[1] z </list><list> m[1][] </list><operator> ge </operator></lex>\
+         <lex><list> x[] </list><list> y[0..1] </list><operator> le </operator></lex>\
+         <lex><list> x[] </list><operator> le </operator></lex>\
+         <lex><list> x[] </list><list> y[] </list><operator> in </operator></lex>\
+         <lex><matrix> m[][] </matrix><operator> le </operator></lex>\
+         <lex><matrix> (x[0],x[1],x[2])(y[0],y[1],z) </matrix><operator> gt </operator></lex>\
+         <lex><matrix> (x[0],x[1],x[2])(y[0],y[1]) </matrix><operator> gt </operator></lex>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    match constraints[0] {
+        XConstraintType::XLex(l) => {
+            assert_eq!(format!("{:?}", l.get_operator()), "Lt");
+            assert_eq!(l.get_lists().len(), 2);
+            assert_eq!(strings(l.get_scope_string()), vec!["x[]", "y[]"]);
+        }
+        c => panic!("{}", c),
+    }
+    match constraints[1] {
+        XConstraintType::XLex(l) => assert_eq!(l.get_lists().len(), 3),
+        c => panic!("{}", c),
+    }
+    assert!(error(constraints[2]).unwrap().contains("different lengths"));
+    // at least two lists and an order operator
+    assert!(error(constraints[3]).is_some());
+    assert!(error(constraints[4]).is_some());
+    match constraints[5] {
+        XConstraintType::XLexMatrix(l) => {
+            assert_eq!(format!("{:?}", l.get_operator()), "Le");
+            assert_eq!(strings(l.get_scope_string()), vec!["m[][]"]);
+        }
+        c => panic!("{}", c),
+    }
+    match constraints[6] {
+        XConstraintType::XLexMatrix(l) => {
+            assert_eq!(l.get_matrix().len(), 2);
+            assert_eq!(strings(l.get_scope_string()).len(), 6);
+        }
+        c => panic!("{}", c),
+    }
+    assert!(error(constraints[7]).unwrap().contains("different lengths"));
+}
+
+#[test]
+fn clause_separates_its_positive_and_negative_literals() {
+    let model = model(
+        "<array id=\"b\" size=\"[3]\"> 0 1 </array> <var id=\"y\"> 0..5 </var>",
+        "<clause><list> b[0] not(b[1]) not(not(b[2])) </list></clause>\
+         <clause> not(b[0]) b[1] </clause>\
+         <group><clause> %0 not(%1) </clause><args> b[0] b[1] </args><args> b[1] b[2] </args></group>\
+         <clause><list> b[0] not(y) </list></clause>\
+         <clause><list> b[0] not(3) </list></clause>\
+         <clause><list> b[0] not(b[1] </list></clause>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    let literals: Vec<String> = constraints[..4]
+        .iter()
+        .map(|c| match c {
+            XConstraintType::XClause(c) => format!(
+                "{:?} {:?}",
+                c.get_positive().iter().map(|(v, _)| v).collect::<Vec<_>>(),
+                c.get_negative().iter().map(|(v, _)| v).collect::<Vec<_>>()
+            ),
+            c => panic!("{}", c),
+        })
+        .collect();
+    assert_eq!(
+        literals,
+        vec![
+            "[\"b[0]\", \"b[2]\"] [\"b[1]\"]",
+            "[\"b[1]\"] [\"b[0]\"]",
+            "[\"b[0]\"] [\"b[1]\"]",
+            "[\"b[1]\"] [\"b[2]\"]",
+        ]
+    );
+    // a literal is a 0/1 variable
+    assert!(error(constraints[4]).unwrap().contains("0/1 variable"));
+    assert!(error(constraints[5])
+        .unwrap()
+        .contains("must be a variable"));
+    assert!(error(constraints[6]).unwrap().contains("literal error"));
+}
+
+#[test]
+fn distance_and_statistic_constraints_read_their_conditions() {
+    let model = model(
+        VARIABLES,
+        "<allDistant><list> x[] </list><condition> (ge,2) </condition></allDistant>\
+         <balance><list> x[] </list><values> 0 1 2 </values><condition> (le,y) </condition></balance>\
+         <balance><list> x[] </list><condition> (eq,0) </condition></balance>\
+         <spread><list> x[] </list><total> 6 </total><condition> (lt,z) </condition></spread>\
+         <deviation><list> x[] </list><condition> (in,0..4) </condition></deviation>\
+         <allDistant><list> x[] </list><condition> (at,2) </condition></allDistant>\
+         <balance><list> x[] </list><condition> (le) </condition></balance>\
+         <spread><list> x[] </list><total> %1 </total><condition> (le,2) </condition></spread>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    match constraints[0] {
+        XConstraintType::XAllDistant(c) => {
+            assert_eq!(format!("{:?}", c.get_operator()), "Ge");
+            assert_eq!(format!("{:?}", c.get_operand()), "Integer(2)");
+            assert_eq!(strings(c.get_scope_string()), vec!["x[]"]);
+        }
+        c => panic!("{}", c),
+    }
+    match (constraints[1], constraints[2]) {
+        (XConstraintType::XBalance(c), XConstraintType::XBalance(d)) => {
+            assert_eq!(
+                strings(c.get_values().as_ref().unwrap()),
+                vec!["0", "1", "2"]
+            );
+            assert_eq!(format!("{:?}", c.get_operand()), "Variable(\"y\")");
+            assert!(d.get_values().is_none());
+        }
+        (c, _) => panic!("{}", c),
+    }
+    match (constraints[3], constraints[4]) {
+        (XConstraintType::XSpread(s), XConstraintType::XDeviation(d)) => {
+            assert!(s.is_spread() && !s.is_deviation());
+            assert_eq!(s.get_total().as_ref().unwrap().to_string(), "6");
+            assert!(d.is_deviation() && d.get_total().is_none());
+            assert_eq!(format!("{:?}", d.get_operator()), "In");
+        }
+        (c, _) => panic!("{}", c),
+    }
+    assert!(error(constraints[5]).unwrap().contains("Operator error"));
+    assert!(error(constraints[6]).unwrap().contains("Operand error"));
+    assert!(error(constraints[7]).unwrap().contains("total error"));
+}
+
+#[test]
+fn large_values_are_read_as_i64() {
+    let model = model(
+        "<array id=\"x\" size=\"[2]\"> 0..10000000000 </array>",
+        "<extension><list> x[] </list><supports> (5000000000,*)(0,10000000000) </supports></extension>\
+         <knapsack><list> x[] </list><weights> 3000000000 1 </weights><profits> 2 4000000000 </profits>\
+         <condition> (le,9000000000) </condition><condition> (ge,0) </condition></knapsack>\
+         <instantiation><list> x[] </list><values> 10000000000 0 </values></instantiation>",
+    );
+    let set = model.build_variables();
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    match constraints[0] {
+        XConstraintType::XExtension(e) => assert_eq!(
+            e.supports_iter().unwrap().cloned().collect::<Vec<_>>(),
+            vec![
+                vec![Some(5000000000), None],
+                vec![Some(0), Some(10000000000)]
+            ]
+        ),
+        c => panic!("{}", c),
+    }
+    match constraints[1] {
+        XConstraintType::XKnapsack(k) => {
+            assert_eq!(k.get_weights(), &vec![3000000000, 1]);
+            assert_eq!(k.get_profits(), &vec![2, 4000000000]);
+            assert_eq!(
+                format!("{:?}", k.get_weight_operand()),
+                "Integer(9000000000)"
+            );
+        }
+        c => panic!("{}", c),
+    }
+    match constraints[2] {
+        XConstraintType::XInstantiation(i) => assert_eq!(i.get_values(), &vec![10000000000, 0]),
+        c => panic!("{}", c),
+    }
+}
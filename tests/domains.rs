@@ -0,0 +1,149 @@
+use xcsp3_rust::variables::xdomain::xcsp3_core::XDomainInteger;
+
+/*
+ * the behavior of the integer domains, their normalization and their set operations
+ */
+
+fn domain(s: &str) -> XDomainInteger {
+    XDomainInteger::from_string(s).unwrap()
+}
+
+fn values(d: &XDomainInteger) -> Vec<i64> {
+    d.iter().collect()
+}
+
+#[test]
+fn normalize_unsorted_and_overlapping_domains() {
+    let d = domain("5 1..3 2 7..9 8");
+    assert_eq!(d.to_string().trim(), "1..3 5 7..9");
+    assert_eq!(values(&d), vec![1, 2, 3, 5, 7, 8, 9]);
+    assert_eq!(d.nb_values(), 7);
+    // an adjacent value is merged into the interval
+    assert_eq!(domain("1..3 4").to_string().trim(), "1..4");
+    let mut d = domain("1..3");
+    d.add_value(0).unwrap();
+    d.add_interval(5, 6).unwrap();
+    d.add_interval(4, 4).unwrap();
+    assert_eq!(d.to_string().trim(), "0..6");
+    // an empty interval is ignored
+    d.add_interval(9, 8).unwrap();
+    assert_eq!(d.nb_values(), 7);
+}
+
+#[test]
+fn strict_parsing_rejects_unsorted_domains() {
+    assert!(XDomainInteger::from_string_strict("1..3 5 7..9").is_ok());
+    assert!(XDomainInteger::from_string_strict("5 1..3").is_err());
+    assert!(XDomainInteger::from_string_strict("1 1").is_err());
+    let mut d = domain("1..3");
+    assert!(d.add_value_strict(3).is_err());
+    assert!(d.add_interval_strict(2, 5).is_err());
+    assert!(d.add_interval_strict(6, 5).is_err());
+    assert!(d.add_interval_strict(5, 6).is_ok());
+    assert_eq!(values(&d), vec![1, 2, 3, 5, 6]);
+}
+
+#[test]
+fn compare_domains_by_their_values() {
+    assert!(domain("1..3 4").equals(&domain("1 2 3 4")));
+    assert!(domain("3 1 2").equals(&domain("1..3")));
+    assert!(!domain("1..3").equals(&domain("1..4")));
+    assert!(XDomainInteger::from_symbolic("a b c").equals(&XDomainInteger::from_symbolic("a b c")));
+    assert!(!XDomainInteger::from_symbolic("a b c").equals(&XDomainInteger::from_symbolic("a c b")));
+}
+
+#[test]
+fn symbolic_domains_are_not_normalized() {
+    let mut d = XDomainInteger::from_symbolic("a b c");
+    assert!(d.add_value(0).is_err());
+    assert!(d.add_interval(0, 1).is_err());
+    assert!(d.contain_symbol());
+    assert_eq!(d.get_symbolic(1), Some("b"));
+}
+
+#[test]
+fn domains_hold_i64_values_and_infinite_bounds() {
+    let d = domain("-5000000000 0..3 4000000000..+infinity");
+    assert_eq!(d.minimum(), Some(-5000000000));
+    assert_eq!(d.maximum(), Some(i64::MAX));
+    assert!(d.contains(-5000000000) && d.contains(i64::MAX - 1));
+    assert!(!d.contains(-1) && !d.contains(3999999999));
+    assert_eq!(
+        d.to_string().trim(),
+        "-5000000000 0..3 4000000000..+infinity"
+    );
+    let d = domain("-infinity..+infinity");
+    assert!(d.contains(i64::MIN) && d.contains(0) && d.contains(i64::MAX));
+    assert_eq!(d.to_string().trim(), "-infinity..+infinity");
+    // an interval starting at +infinity is empty
+    assert!(domain("+infinity..0").is_empty());
+    assert!(XDomainInteger::from_string_strict("+infinity..0").is_err());
+    assert!(XDomainInteger::from_string("9223372036854775808").is_err());
+}
+
+#[test]
+fn intersect_and_unite_domains() {
+    let a = domain("0..5 8 10..12");
+    let b = domain("3..9 11");
+    assert_eq!(values(&a.intersect(&b).unwrap()), vec![3, 4, 5, 8, 11]);
+    assert_eq!(a.union(&b).unwrap().to_string().trim(), "0..12");
+    assert!(a.intersect(&domain("6 7 9")).unwrap().is_empty());
+    assert!(a.contains(11) && !a.contains(9));
+    // the set operations do not map the symbols
+    let s = XDomainInteger::from_symbolic("a b c");
+    assert!(a.intersect(&s).is_err());
+    assert!(s.union(&a).is_err());
+}
+
+#[test]
+fn remove_and_restrict_values() {
+    let mut d = domain("0..10");
+    d.remove_value(5).unwrap();
+    d.remove_interval(8, 20).unwrap();
+    assert_eq!(d.to_string().trim(), "0..4 6..7");
+    // removing values out of the domain or an empty interval changes nothing
+    d.remove_value(42).unwrap();
+    d.remove_interval(3, 2).unwrap();
+    assert_eq!(d.nb_values(), 7);
+    d.restrict_to_bounds(2, 6).unwrap();
+    assert_eq!(values(&d), vec![2, 3, 4, 6]);
+    d.restrict_to_bounds(7, 9).unwrap();
+    assert!(d.is_empty());
+    assert_eq!((d.minimum(), d.maximum()), (None, None));
+    let mut s = XDomainInteger::from_symbolic("a b c");
+    assert!(s.remove_value(0).is_err());
+    assert!(s.restrict_to_bounds(0, 1).is_err());
+    // there is no value after i64::MAX
+    let mut d = domain("0..+infinity");
+    d.remove_value(i64::MAX).unwrap();
+    assert_eq!(d.maximum(), Some(i64::MAX - 1));
+}
+
+#[test]
+fn index_the_values_of_a_domain() {
+    let d = domain("-2..0 5 7..8");
+    let nth: Vec<Option<i64>> = (0..7).map(|n| d.nth_value(n)).collect();
+    assert_eq!(
+        nth,
+        vec![Some(-2), Some(-1), Some(0), Some(5), Some(7), Some(8), None]
+    );
+    for (i, v) in d.iter().enumerate() {
+        assert_eq!(d.index_of(v), Some(i));
+    }
+    assert_eq!(d.index_of(6), None);
+    assert_eq!(d.index_of(9), None);
+}
+
+#[test]
+fn view_small_domains_as_bitsets() {
+    let d = domain("-3..-1 2 60..70");
+    let bitset = d.to_bitset(100).unwrap();
+    assert_eq!((bitset.get_min(), bitset.get_max()), (-3, 70));
+    assert_eq!(bitset.size(), d.nb_values());
+    assert_eq!(bitset.iter().collect::<Vec<i64>>(), values(&d));
+    assert!(bitset.contains(2) && !bitset.contains(0) && !bitset.contains(71));
+    // the domain is wider than the limit, or empty
+    assert!(d.to_bitset(73).is_none());
+    assert!(d.to_bitset(74).is_some());
+    assert!(domain("").to_bitset(10).is_none());
+}
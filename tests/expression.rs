@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use xcsp3_rust::constraints::xconstraint_trait::xcsp3_core::XConstraintTrait;
+use xcsp3_rust::constraints::xconstraint_type::xcsp3_core::XConstraintType;
+use xcsp3_rust::constraints::xprimitive::xcsp3_core::XPrimitive;
+use xcsp3_rust::objectives::xobjectives_type::xcsp3_core::{XObjective, XObjectivesType};
+use xcsp3_rust::xcsp_xml::xcsp_xml_model::xcsp3_xml::XcspXmlModel;
+
+/*
+ * the behavior of the evaluation, the simplification, the primitive recognition,
+ * the linear extraction and the bounds of the intension expressions
+ */
+
+fn model(variables: &str, constraints: &str, objectives: &str) -> XcspXmlModel {
+    let xml = format!(
+        "<instance format=\"XCSP3\" type=\"COP\"><variables>{}</variables>\
+         <constraints>{}</constraints><objectives>{}</objectives></instance>",
+        variables, constraints, objectives
+    );
+    XcspXmlModel::from_string(&xml).unwrap()
+}
+
+const VARIABLES: &str = "<var id=\"x\"> -10..10 </var> <var id=\"y\"> -2..3 </var> \
+    <var id=\"z\"> 0..2 </var> <var id=\"a\"> 0 1 </var> <var id=\"b\"> 0 1 </var>";
+
+/// the intension constraints of the model, in their order
+fn intensions(constraints: &[&str]) -> Vec<String> {
+    constraints
+        .iter()
+        .map(|c| format!("<intension> {} </intension>", c))
+        .collect()
+}
+
+/// the bounds of the expressions, each one is the objective to minimize
+fn bounds(variables: &str, expressions: &[&str]) -> Vec<Result<(i64, i64), String>> {
+    let objectives: String = expressions
+        .iter()
+        .map(|e| format!("<minimize> {} </minimize>", e))
+        .collect();
+    let model = model(variables, "", &objectives);
+    let set = model.build_variables();
+    model
+        .build_objectives(&set)
+        .iter()
+        .map(|o| match o {
+            XObjectivesType::Minimize(XObjective::XObjectiveExpression(e)) => {
+                e.get_bounds().map_err(|e| e.to_string())
+            }
+            _ => Err(o.to_string()),
+        })
+        .collect()
+}
+
+#[test]
+fn evaluate_expressions() {
+    let model = model(
+        VARIABLES,
+        &intensions(&[
+            "eq(add(x,mul(2,y)),z)",
+            "lt(div(x,y),if(a,x,y))",
+            "eq(x,div(y,z))",
+        ])
+        .concat(),
+        "",
+    );
+    let set = model.build_variables();
+    let mut assignment: HashMap<String, i64> = HashMap::new();
+    for (id, value) in [("x", 4), ("y", -1), ("z", 2), ("a", 1), ("b", 0)] {
+        assignment.insert(id.to_string(), value);
+    }
+    let values: Vec<Result<i64, String>> = model
+        .build_constraints(&set)
+        .iter()
+        .map(|c| match c {
+            XConstraintType::XIntention(i) => i
+                .get_expression()
+                .evaluate(&assignment)
+                .map_err(|e| e.to_string()),
+            _ => Err(c.to_string()),
+        })
+        .collect();
+    assert_eq!(values[0], Ok(1));
+    assert_eq!(values[1], Ok(1));
+    assert_eq!(values[2], Ok(0));
+    assignment.insert("z".to_string(), 0);
+    let constraints = model.build_constraints(&set);
+    if let Some(XConstraintType::XIntention(i)) = constraints.iter().nth(2) {
+        assert!(i.get_expression().evaluate(&assignment).is_err());
+    }
+}
+
+#[test]
+fn simplify_expressions() {
+    let model = model(
+        VARIABLES,
+        &intensions(&[
+            "eq(add(x,0,mul(1,y)),add(2,3))",
+            "or(a,0,not(not(eq(b,1))))",
+            "not(not(b))",
+        ])
+        .concat(),
+        "",
+    );
+    let set = model.build_variables();
+    let simplified: Vec<String> = model
+        .build_constraints(&set)
+        .iter_mut()
+        .map(|c| match c {
+            XConstraintType::XIntention(i) => {
+                i.simplify();
+                i.get_expression().get_root().to_expression()
+            }
+            _ => c.to_string(),
+        })
+        .collect();
+    // a variable is not a boolean, so not(not(b)) is kept
+    assert_eq!(
+        simplified,
+        vec!["eq(add(x,y),5)", "or(a,eq(b,1))", "not(not(b))"]
+    );
+}
+
+#[test]
+fn recognize_primitives() {
+    let model = model(
+        VARIABLES,
+        &intensions(&[
+            "gt(3,x)",
+            "le(y,add(x,1))",
+            "eq(mul(y,z),x)",
+            "imp(a,b)",
+            "or(eq(a,0),ne(b,0))",
+            "or(a,eq(z,1))",
+            "not(in(x,set(1,3)))",
+        ])
+        .concat(),
+        "",
+    );
+    let set = model.build_variables();
+    let primitives: Vec<Option<XPrimitive>> = model
+        .build_constraints(&set)
+        .iter()
+        .map(|c| match c {
+            XConstraintType::XIntention(i) => i.get_primitive(),
+            _ => None,
+        })
+        .collect();
+    // the constant is on the left, so the operator is mirrored
+    match &primitives[0] {
+        Some(XPrimitive::Unary { x, operator, k }) => {
+            assert_eq!(
+                (x.as_str(), format!("{:?}", operator), *k),
+                ("x", "Lt".to_string(), 3)
+            )
+        }
+        p => panic!("{:?}", p),
+    }
+    match &primitives[1] {
+        Some(XPrimitive::Binary { x, operator, y, k }) => assert_eq!(
+            (x.as_str(), format!("{:?}", operator), y.as_str(), *k),
+            ("y", "Le".to_string(), "x", 1)
+        ),
+        p => panic!("{:?}", p),
+    }
+    // the result of the arithmetic operator is on the right
+    match &primitives[2] {
+        Some(XPrimitive::Ternary { x, y, operator, z }) => assert_eq!(
+            (
+                x.as_str(),
+                y.as_str(),
+                format!("{:?}", operator),
+                z.as_str()
+            ),
+            ("x", "y", "Mul".to_string(), "z")
+        ),
+        p => panic!("{:?}", p),
+    }
+    // imp(a,b) is the clause not(a) or b
+    match &primitives[3] {
+        Some(XPrimitive::Clause { positive, negative }) => {
+            assert_eq!(
+                (positive.clone(), negative.clone()),
+                (vec!["b".to_string()], vec!["a".to_string()])
+            )
+        }
+        p => panic!("{:?}", p),
+    }
+    match &primitives[4] {
+        Some(XPrimitive::Clause { positive, negative }) => {
+            assert_eq!(
+                (positive.clone(), negative.clone()),
+                (vec!["b".to_string()], vec!["a".to_string()])
+            )
+        }
+        p => panic!("{:?}", p),
+    }
+    // z is not a 0/1 variable, so eq(z,1) is not a literal
+    assert!(primitives[5].is_none());
+    match &primitives[6] {
+        Some(XPrimitive::Set {
+            x,
+            operator,
+            values,
+        }) => assert_eq!(
+            (x.as_str(), format!("{:?}", operator), values.clone()),
+            ("x", "Notin".to_string(), vec![1, 3])
+        ),
+        p => panic!("{:?}", p),
+    }
+}
+
+/// the scope, the coefficients, the operator and the operand of a sum
+type Sum = (Vec<String>, Vec<String>, String, String);
+
+#[test]
+fn extract_linear_forms() {
+    let model = model(
+        VARIABLES,
+        &intensions(&[
+            "le(add(x,mul(2,x),y,neg(y)),sub(z,3))",
+            "eq(sub(mul(3,x),mul(3,x)),y)",
+            "in(add(x,3),set(5,7))",
+            "le(mul(x,y),3)",
+        ])
+        .concat(),
+        "",
+    );
+    let set = model.build_variables();
+    let mut constraints = model.build_constraints(&set);
+    let sums: Vec<Result<Sum, String>> = constraints
+        .iter_mut()
+        .map(|c| match c {
+            XConstraintType::XIntention(i) => match i.to_sum() {
+                Ok(s) => Ok((
+                    s.get_scope_string().iter().map(|e| e.to_string()).collect(),
+                    match s.get_coeffs() {
+                        Some(coeffs) => coeffs.iter().map(|e| e.to_string()).collect(),
+                        None => vec![],
+                    },
+                    format!("{:?}", s.get_operator()),
+                    format!("{:?}", s.get_operand()),
+                )),
+                Err(e) => Err(e.to_string()),
+            },
+            _ => Err(c.to_string()),
+        })
+        .collect();
+    // x + 2x merges into 3x, y - y cancels, and z moves to the left with the constant to the right
+    let (scope, coeffs, operator, operand) = sums[0].clone().unwrap();
+    assert_eq!(scope, vec!["x", "z"]);
+    assert_eq!(coeffs, vec!["3", "-1"]);
+    assert_eq!((operator.as_str(), operand.as_str()), ("Le", "Integer(-3)"));
+    // 3x - 3x cancels, only y is left
+    let (scope, coeffs, operator, operand) = sums[1].clone().unwrap();
+    assert_eq!(scope, vec!["y"]);
+    assert_eq!(coeffs, vec!["-1"]);
+    assert_eq!((operator.as_str(), operand.as_str()), ("Eq", "Integer(0)"));
+    // the offset 3 is folded into the set, x in {2, 4}
+    let (scope, coeffs, operator, operand) = sums[2].clone().unwrap();
+    assert_eq!(
+        (scope, coeffs, operator.as_str()),
+        (vec!["x".to_string()], vec![], "In")
+    );
+    let mut values: Vec<i64> = operand
+        .trim_start_matches("SetInteger({")
+        .trim_end_matches("})")
+        .split(", ")
+        .map(|v| v.parse::<i64>().unwrap())
+        .collect();
+    values.sort_unstable();
+    assert_eq!(values, vec![2, 4]);
+    assert!(sums[3].is_err());
+}
+
+#[test]
+fn bound_divisions_when_the_divisor_spans_zero() {
+    let variables = "<var id=\"x\"> 4..9 </var> <var id=\"y\"> 0..2 </var> \
+        <var id=\"n\"> -7..7 </var> <var id=\"d\"> -3..2 </var> <var id=\"o\"> 0 </var>";
+    assert_eq!(
+        bounds(variables, &["div(x,y)", "div(n,d)", "mod(n,d)", "mod(x,d)", "div(x,o)", "mod(x,o)"]),
+        vec![
+            // 0 is not a divisor, so x is divided by 1 or 2
+            Ok((2, 9)),
+            Ok((-7, 7)),
+            // the remainder has the sign of the dividend and |n mod d| < 3
+            Ok((-2, 2)),
+            Ok((0, 2)),
+            Err("EvaluationError: division by zero,  please visit http://xcsp.org/specifications/constraints/generic/intension/".to_string()),
+            Err("EvaluationError: division by zero,  please visit http://xcsp.org/specifications/constraints/generic/intension/".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn bound_powers_when_the_base_spans_zero() {
+    let variables = "<var id=\"x\"> -2..3 </var> <var id=\"e\"> 0..2 </var>";
+    assert_eq!(
+        bounds(variables, &["pow(x,3)", "pow(x,2)", "pow(x,e)", "pow(x,0)"]),
+        vec![Ok((-8, 27)), Ok((0, 9)), Ok((-2, 9)), Ok((1, 1))]
+    );
+}
+
+#[test]
+fn bound_expressions_at_the_limits_of_i64() {
+    let variables = "<var id=\"x\"> -9223372036854775808..9223372036854775807 </var> \
+        <var id=\"y\"> -infinity..+infinity </var> <var id=\"z\"> 9223372036854775806 9223372036854775807 </var>";
+    assert_eq!(
+        bounds(
+            variables,
+            &["add(x,1)", "neg(x)", "abs(y)", "mul(x,y)", "add(z,z)", "pow(z,2)", "sub(0,z)"]
+        ),
+        vec![
+            Ok((i64::MIN + 1, i64::MAX)),
+            Ok((-i64::MAX, i64::MAX)),
+            Ok((0, i64::MAX)),
+            Ok((i64::MIN, i64::MAX)),
+            Ok((i64::MAX, i64::MAX)),
+            Ok((i64::MAX, i64::MAX)),
+            Ok((-i64::MAX, 1 - i64::MAX)),
+        ]
+    );
+}
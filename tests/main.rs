@@ -51,6 +51,10 @@ fn test_single() {
             XVariableType::XVariableArray(_) => {}
             XVariableType::XVariableInt(_) => {}
             XVariableType::XVariableTree(_) => {}
+            XVariableType::XVariableSetVar(_) => {}
+            XVariableType::XVariableSetArray(_) => {}
+            XVariableType::XVariableReal(_) => {}
+            XVariableType::XVariableRealArray(_) => {}
         }
     }
     println!("constraints:");
@@ -81,6 +85,20 @@ fn test_single() {
             XConstraintType::XNoOverlap(_) => {}
             XConstraintType::XStretch(_) => {}
             XConstraintType::XNoOverlapKDim(_) => {}
+            XConstraintType::XCircuit(_) => {}
+            XConstraintType::XLex(_) => {}
+            XConstraintType::XLexMatrix(_) => {}
+            XConstraintType::XPrecedence(_) => {}
+            XConstraintType::XBinPacking(_) => {}
+            XConstraintType::XClause(_) => {}
+            XConstraintType::XAllDistant(_) => {}
+            XConstraintType::XBalance(_) => {}
+            XConstraintType::XSpread(_) => {}
+            XConstraintType::XDeviation(_) => {}
+            XConstraintType::XKnapsack(_) => {}
+            XConstraintType::XFlow(_) => {}
+            XConstraintType::XMetaConstraint(_) => {}
+            XConstraintType::XSoftConstraint(_) => {}
         }
     }
     println!("objectives:");
@@ -98,6 +116,16 @@ fn test_single() {
             },
         }
     }
+    println!("annotations:");
+    match model.build_annotations(&variable) {
+        Ok(a) => println!("\t{}", a),
+        Err(e) => println!("\t{}", e),
+    }
+    println!("quantification:");
+    match model.build_quantification(&variable) {
+        Ok(q) => println!("\t{}", q),
+        Err(e) => println!("\t{}", e),
+    }
 }
 
 fn test_all() {
@@ -0,0 +1,190 @@
+use xcsp3_rust::annotations::xannotations::xcsp3_core::XHeuristicType;
+use xcsp3_rust::frameworks::xquantification::xcsp3_core::XQuantifierType;
+use xcsp3_rust::objectives::xobjectives_type::xcsp3_core::{XCombinationType, XObjectivesType};
+use xcsp3_rust::xcsp_xml::xcsp_xml_model::xcsp3_xml::{InstanceType, XcspXmlModel};
+
+/*
+ * the behavior of the sections of an instance around the variables and the constraints,
+ * the objectives, the annotations and the frameworks
+ */
+
+fn model(instance: &str, variables: &str, sections: &str) -> XcspXmlModel {
+    let xml = format!(
+        "<instance format=\"XCSP3\" {}><variables>{}</variables>\
+         <constraints></constraints>{}</instance>",
+        instance, variables, sections
+    );
+    XcspXmlModel::from_string(&xml).unwrap()
+}
+
+const VARIABLES: &str = "<array id=\"x\" size=\"[3]\"> 0..9 </array> <var id=\"y\"> 0..9 </var>";
+
+/// the kinds of the objectives, in their order
+fn kinds(model: &XcspXmlModel) -> (Vec<&'static str>, Result<XCombinationType, String>) {
+    let set = model.build_variables();
+    let objectives = model.build_objectives(&set);
+    let kinds = objectives
+        .iter()
+        .map(|o| match o {
+            XObjectivesType::Minimize(_) => "min",
+            XObjectivesType::Maximize(_) => "max",
+            XObjectivesType::XObjectiveNone(_) => "none",
+        })
+        .collect();
+    let combination = objectives
+        .get_combination()
+        .as_ref()
+        .map(|c| *c)
+        .map_err(|e| e.to_string());
+    (kinds, combination)
+}
+
+#[test]
+fn objectives_keep_their_order_and_combination() {
+    let objectives = "<minimize> y </minimize><maximize type=\"sum\"> x[] </maximize>\
+        <minimize type=\"maximum\"><list> x[] </list></minimize>";
+    let (kinds_of, combination) = kinds(&model(
+        "type=\"COP\"",
+        VARIABLES,
+        &format!(
+            "<objectives combination=\"lexico\">{}</objectives>",
+            objectives
+        ),
+    ));
+    assert_eq!(kinds_of, vec!["min", "max", "min"]);
+    assert_eq!(combination, Ok(XCombinationType::Lexico));
+    let (kinds_of, combination) = kinds(&model(
+        "type=\"COP\"",
+        VARIABLES,
+        &format!("<objectives>{}</objectives>", objectives),
+    ));
+    assert_eq!(kinds_of, vec!["min", "max", "min"]);
+    assert_eq!(combination, Ok(XCombinationType::Pareto));
+    // an invalid combination does not change the objectives
+    let (kinds_of, combination) = kinds(&model(
+        "type=\"COP\"",
+        VARIABLES,
+        &format!(
+            "<objectives combination=\"sum\">{}</objectives>",
+            objectives
+        ),
+    ));
+    assert_eq!(kinds_of, vec!["min", "max", "min"]);
+    assert!(combination.unwrap_err().contains("lexico or pareto"));
+}
+
+#[test]
+fn quantified_instances_keep_their_blocks() {
+    let qcsp = model(
+        "type=\"QCSP\"",
+        VARIABLES,
+        "<quantification><exists> x[0] </exists><forall> x[1..2] y </forall></quantification>",
+    );
+    assert_eq!(qcsp.get_instance_type(), &InstanceType::Qcsp);
+    let set = qcsp.build_variables();
+    let quantification = qcsp.build_quantification(&set).unwrap();
+    let blocks: Vec<(XQuantifierType, Vec<String>)> = quantification
+        .get_blocks()
+        .iter()
+        .map(|b| (b.get_quantifier().clone(), b.get_vars().clone()))
+        .collect();
+    assert_eq!(
+        blocks,
+        vec![
+            (XQuantifierType::Exists, vec!["x[0]".to_string()]),
+            (
+                XQuantifierType::Forall,
+                vec!["x[1]".to_string(), "x[2]".to_string(), "y".to_string()]
+            ),
+        ]
+    );
+    // a block needs variables, and known ones
+    for blocks in [
+        "<exists/><forall> y </forall>",
+        "<exists> </exists>",
+        "<forall> z </forall>",
+    ] {
+        let qcsp = model(
+            "type=\"QCSP\"",
+            VARIABLES,
+            &format!("<quantification>{}</quantification>", blocks),
+        );
+        let set = qcsp.build_variables();
+        assert!(qcsp.build_quantification(&set).is_err(), "{}", blocks);
+    }
+    // the other frameworks have no quantification
+    let csp = model("type=\"CSP\"", VARIABLES, "");
+    let set = csp.build_variables();
+    assert!(csp.build_quantification(&set).unwrap().is_empty());
+}
+
+#[test]
+fn weighted_instances_keep_their_cost_bounds() {
+    let wcsp = model("type=\"WCSP\" lb=\"2\" ub=\"10\"", VARIABLES, "");
+    assert_eq!(wcsp.get_instance_type(), &InstanceType::Wcsp);
+    let bounds = wcsp.get_cost_bounds().unwrap();
+    assert_eq!((bounds.get_lb(), bounds.get_ub()), (&Some(2), &Some(10)));
+    let bounds = model("type=\"WCSP\"", VARIABLES, "")
+        .get_cost_bounds()
+        .unwrap();
+    assert_eq!((bounds.get_lb(), bounds.get_ub()), (&None, &None));
+    assert!(model("type=\"WCSP\" ub=\"ten\"", VARIABLES, "")
+        .get_cost_bounds()
+        .is_err());
+    assert_eq!(
+        model("type=\"Max-CSP\"", VARIABLES, "").get_instance_type(),
+        &InstanceType::MaxCsp
+    );
+}
+
+#[test]
+fn annotations_resolve_their_variables() {
+    let csp = model(
+        "type=\"CSP\"",
+        VARIABLES,
+        "<annotations><decision> x[] </decision><output> y x[0] </output>\
+         <varHeuristic lc=\"2\"><static order=\"2 0 1\"> x[] </static><min type=\"dom\"> y </min></varHeuristic>\
+         <valHeuristic><random> y </random></valHeuristic>\
+         <filtering type=\"AC\"> c1 c2 </filtering><prepro consistency=\"SAC\"> c3 </prepro></annotations>",
+    );
+    let set = csp.build_variables();
+    let annotations = csp.build_annotations(&set).unwrap();
+    assert_eq!(annotations.get_decision(), &vec!["x[0]", "x[1]", "x[2]"]);
+    assert_eq!(annotations.get_output(), &vec!["y", "x[0]"]);
+    let var_heuristic = annotations.get_var_heuristic().as_ref().unwrap();
+    assert_eq!(var_heuristic.get_lc(), &Some(2));
+    let parts = var_heuristic.get_parts();
+    assert_eq!(parts[0].get_kind(), &XHeuristicType::Static);
+    assert_eq!(parts[0].get_order(), &vec![2, 0, 1]);
+    assert_eq!(parts[0].get_vars().len(), 3);
+    assert_eq!(parts[1].get_kind(), &XHeuristicType::Min);
+    assert_eq!(parts[1].get_criterion().as_deref(), Some("dom"));
+    let val_heuristic = annotations.get_val_heuristic().as_ref().unwrap();
+    assert_eq!(
+        val_heuristic.get_parts()[0].get_kind(),
+        &XHeuristicType::Random
+    );
+    assert_eq!(annotations.get_filtering()[0].get_type(), "AC");
+    assert_eq!(
+        annotations.get_filtering()[0].get_constraints(),
+        &vec!["c1", "c2"]
+    );
+    assert_eq!(annotations.get_prepro()[0].get_consistency(), "SAC");
+
+    // an instance without annotations has empty ones
+    let csp = model("type=\"CSP\"", VARIABLES, "");
+    let set = csp.build_variables();
+    let annotations = csp.build_annotations(&set).unwrap();
+    assert!(annotations.get_decision().is_empty() && annotations.get_var_heuristic().is_none());
+
+    // the unknown variables and the bad heuristics are errors
+    for sections in [
+        "<annotations><decision> w </decision></annotations>",
+        "<annotations><varHeuristic lc=\"a\"><random> y </random></varHeuristic></annotations>",
+        "<annotations><valHeuristic><static order=\"a\"> y </static></valHeuristic></annotations>",
+    ] {
+        let csp = model("type=\"CSP\"", VARIABLES, sections);
+        let set = csp.build_variables();
+        assert!(csp.build_annotations(&set).is_err(), "{}", sections);
+    }
+}
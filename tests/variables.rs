@@ -0,0 +1,193 @@
+use xcsp3_rust::constraints::xconstraint_type::xcsp3_core::XConstraintType;
+use xcsp3_rust::variables::xvariable_type::xcsp3_core::XVariableType;
+use xcsp3_rust::xcsp_xml::xcsp_xml_model::xcsp3_xml::XcspXmlModel;
+
+/*
+ * the behavior of the variables which are not integer ones, and of the way the arrays
+ * resolve their variables
+ */
+
+fn model(variables: &str, constraints: &str) -> XcspXmlModel {
+    let xml = format!(
+        "<instance format=\"XCSP3\" type=\"CSP\"><variables>{}</variables>\
+         <constraints>{}</constraints></instance>",
+        variables, constraints
+    );
+    XcspXmlModel::from_string(&xml).unwrap()
+}
+
+#[test]
+fn set_variables_keep_their_required_and_possible_values() {
+    let model = model(
+        "<var id=\"s\" type=\"set\"><required> 1 2 </required><possible> 1..5 </possible></var>\
+         <array id=\"t\" type=\"set\" size=\"[2]\"><required></required><possible> 0..3 </possible></array>\
+         <var id=\"n\"> 0..3 </var>\
+         <var id=\"u\" type=\"set\"><required> 1 </required><possible> 1..a </possible></var>",
+        "<intension> subset(s,t[0]) </intension><intension> eq(card(union(s,t[1])),n) </intension>",
+    );
+    let set = model.build_variables();
+    let (id, domain) = &set.construct_set_scope(&[&"s".to_string()]).unwrap()[0];
+    assert_eq!(id, "s");
+    assert_eq!((domain.min_card(), domain.max_card()), (2, 5));
+    assert_eq!(domain.get_required().to_string().trim(), "1..2");
+    let array = set.construct_set_scope(&[&"t[]".to_string()]).unwrap();
+    assert_eq!(
+        array.iter().map(|(id, _)| id.as_str()).collect::<Vec<_>>(),
+        vec!["t[0]", "t[1]"]
+    );
+    assert_eq!((array[1].1.min_card(), array[1].1.max_card()), (0, 4));
+    // an integer variable is not a set variable, and a bad domain is not built
+    assert!(set.construct_set_scope(&[&"n".to_string()]).is_err());
+    assert!(matches!(
+        set.iter().last(),
+        Some(XVariableType::XVariableNone(_))
+    ));
+
+    // the set operators are read by the expressions
+    let constraints = model.build_constraints(&set);
+    let functions: Vec<String> = constraints.iter().map(|c| c.to_string()).collect();
+    assert!(functions[0].contains("Subset(s,t[0],)"), "{}", functions[0]);
+    assert!(
+        functions[1].contains("Card(Union(s,t[1],)"),
+        "{}",
+        functions[1]
+    );
+    assert!(constraints
+        .iter()
+        .all(|c| !matches!(c, XConstraintType::XConstraintNone(_))));
+}
+
+#[test]
+fn real_variables_keep_their_intervals() {
+    let model = model(
+        "<var id=\"r\" type=\"real\"> [1.5,3.25] </var>\
+         <array id=\"q\" type=\"real\" size=\"[2]\"> ]0,+infinity[ 5.5 </array>\
+         <var id=\"n\"> 0..3 </var>\
+         <var id=\"e\" type=\"real\"> [3,1] </var>\
+         <var id=\"f\" type=\"real\"> [a,1] </var>",
+        "<intension> le(fdiv(r,2.5),sqrt(q[0])) </intension><intension> gt(exp(r),-1.5e1) </intension>",
+    );
+    let set = model.build_variables();
+    let (_, domain) = &set.construct_real_scope(&[&"r".to_string()]).unwrap()[0];
+    assert_eq!((domain.minimum(), domain.maximum()), (1.5, 3.25));
+    assert!(domain.contains(1.5) && domain.contains(2.0) && !domain.contains(3.5));
+    let array = set.construct_real_scope(&[&"q[1]".to_string()]).unwrap();
+    let (id, domain) = &array[0];
+    assert_eq!(id, "q[1]");
+    let open = &domain.get_intervals()[0];
+    assert!(open.is_min_open() && open.is_max_open());
+    assert_eq!(open.maximum(), f64::INFINITY);
+    assert!(!domain.contains(0.0) && domain.contains(0.5) && domain.contains(5.5));
+    assert!(set.construct_real_scope(&[&"n".to_string()]).is_err());
+    // the reversed and the unreadable intervals are not built
+    let none: Vec<bool> = set
+        .iter()
+        .map(|v| matches!(v, XVariableType::XVariableNone(_)))
+        .collect();
+    assert_eq!(none, vec![false, false, false, true, true]);
+
+    // the real operators and constants are read by the expressions
+    let constraints = model.build_constraints(&set);
+    let functions: Vec<String> = constraints.iter().map(|c| c.to_string()).collect();
+    assert!(
+        functions[0].contains("Le(Fdiv(r,2.5,)Sqrt(q[0],))"),
+        "{}",
+        functions[0]
+    );
+    assert!(functions[1].contains("Exp(r,)-15"), "{}", functions[1]);
+}
+
+/// the ids of the variables of a list, resolved by the set of variables
+fn ids(model: &XcspXmlModel, list: &str) -> Result<Vec<String>, String> {
+    let set = model.build_variables();
+    let list: Vec<String> = list.split_whitespace().map(String::from).collect();
+    set.construct_scope(&list.iter().collect::<Vec<_>>())
+        .map(|scope| scope.into_iter().map(|(id, _)| id).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn arrays_are_indexed_from_their_start_index() {
+    let model = model(
+        "<array id=\"x\" size=\"[3]\" startIndex=\"1\"> 0..3 </array>\
+         <array id=\"m\" size=\"[3][2]\" startIndex=\"1\"> 0..3 </array>\
+         <array id=\"t\" size=\"[2][2]\" startIndex=\"1\"><domain for=\"t[1][]\"> 0 1 </domain>\
+         <domain for=\"others\"> 5 </domain></array>\
+         <array id=\"z\" size=\"[2]\"> 0..3 </array>\
+         <array id=\"w\" size=\"[2]\" startIndex=\"a\"> 0..3 </array>",
+        "",
+    );
+    assert_eq!(ids(&model, "x[]").unwrap(), vec!["x[1]", "x[2]", "x[3]"]);
+    assert_eq!(ids(&model, "x[3] z[0]").unwrap(), vec!["x[3]", "z[0]"]);
+    let mut range = ids(&model, "m[2..3][]").unwrap();
+    range.sort();
+    assert_eq!(range, vec!["m[2][1]", "m[2][2]", "m[3][1]", "m[3][2]"]);
+    assert_eq!(ids(&model, "t[2][]").unwrap(), vec!["t[2][1]", "t[2][2]"]);
+    let set = model.build_variables();
+    let tree = set
+        .construct_scope(&[&"t[1][2]".to_string(), &"t[2][1]".to_string()])
+        .unwrap();
+    assert_eq!(tree[0].1.to_string().trim(), "0..1");
+    assert_eq!(tree[1].1.to_string().trim(), "5");
+    // the indexes below the start index or after the last variable are errors
+    assert!(ids(&model, "x[0]").is_err());
+    assert!(ids(&model, "x[4]").is_err());
+    assert!(ids(&model, "m[1][3]").is_err());
+    assert!(ids(&model, "z[2]").is_err());
+    assert!(matches!(
+        set.iter().last(),
+        Some(XVariableType::XVariableNone(_))
+    ));
+}
+
+#[test]
+fn symbolic_values_are_mapped_to_their_indexes() {
+    let model = model(
+        "<array id=\"c\" size=\"[2]\" type=\"symbolic\"> red green blue </array>\
+         <var id=\"s\" type=\"symbolic\"> low high </var>",
+        "<extension><list> c[0] s </list><supports> (red,high)(blue,*) </supports></extension>\
+         <extension><list> c[0] </list><conflicts> green </conflicts></extension>\
+         <instantiation><list> c[] s </list><values> blue red low </values></instantiation>\
+         <intension> eq(c[1],blue) </intension>\
+         <extension><list> c[0] s </list><supports> (red,pink) </supports></extension>\
+         <instantiation><list> c[] </list><values> blue </values></instantiation>",
+    );
+    let set = model.build_variables();
+    assert_eq!(set.get_symbolic("c[1]", 2), Some("blue"));
+    assert_eq!(set.get_symbolic("s", 0), Some("low"));
+    assert_eq!(set.get_symbolic("s", 2), None);
+    let constraints = model.build_constraints(&set);
+    let constraints: Vec<&XConstraintType> = constraints.iter().collect();
+    match (constraints[0], constraints[1]) {
+        (XConstraintType::XExtension(s), XConstraintType::XExtension(c)) => {
+            assert_eq!(
+                s.supports_iter().unwrap().cloned().collect::<Vec<_>>(),
+                vec![vec![Some(0), Some(1)], vec![Some(2), None]]
+            );
+            assert_eq!(
+                c.conflicts_iter().unwrap().cloned().collect::<Vec<_>>(),
+                vec![vec![Some(1)]]
+            );
+        }
+        (c, _) => panic!("{}", c),
+    }
+    match constraints[2] {
+        XConstraintType::XInstantiation(i) => assert_eq!(i.get_values(), &vec![2, 0, 0]),
+        c => panic!("{}", c),
+    }
+    match constraints[3] {
+        XConstraintType::XIntention(i) => {
+            assert!(i.to_string().contains("Eq(c[1],2,)"), "{}", i)
+        }
+        c => panic!("{}", c),
+    }
+    // an unknown symbol or a missing one is an error
+    assert!(matches!(
+        constraints[4],
+        XConstraintType::XConstraintNone(_)
+    ));
+    assert!(matches!(
+        constraints[5],
+        XConstraintType::XConstraintNone(_)
+    ));
+}